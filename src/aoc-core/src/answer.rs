@@ -0,0 +1,98 @@
+/// A value a solver can return: formatted the way the answer is submitted and
+/// written to the `.out` files.
+///
+/// Implemented for integers, strings, `char`, tuples and grids of `char` or ASCII `u8`.
+/// Implement it for a custom return type to control how its answer is written. A solver
+/// that may have no answer returns an `Option` of one of these, which the
+/// `advent_of_code` attribute unwraps.
+pub trait Answer {
+    fn to_answer(&self) -> String;
+}
+
+macro_rules! display_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl Answer for $ty {
+                fn to_answer(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, str, String);
+
+impl<T: Answer + ?Sized> Answer for &T {
+    fn to_answer(&self) -> String {
+        (**self).to_answer()
+    }
+}
+
+/// Tuples are comma separated, like the coordinates `6,1`
+macro_rules! tuple_answer {
+    ($($name:ident),+) => {
+        impl<$($name: Answer),+> Answer for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn to_answer(&self) -> String {
+                let ($($name,)+) = self;
+                [$($name.to_answer()),+].join(",")
+            }
+        }
+    };
+}
+
+tuple_answer!(A, B);
+tuple_answer!(A, B, C);
+tuple_answer!(A, B, C, D);
+
+/// A grid of characters or bytes is written one row per line
+impl<C: Copy + Into<char>> Answer for [Vec<C>] {
+    fn to_answer(&self) -> String {
+        self.iter()
+            .map(|row| row.iter().map(|&cell| cell.into()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<C: Copy + Into<char>> Answer for Vec<Vec<C>> {
+    fn to_answer(&self) -> String {
+        self.as_slice().to_answer()
+    }
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strings_are_not_quoted() {
+        assert_eq!("co,de,ka,ta".to_string().to_answer(), "co,de,ka,ta");
+        assert_eq!("abc".to_answer(), "abc");
+        assert_eq!('x'.to_answer(), "x");
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(42usize.to_answer(), "42");
+        assert_eq!((-7i64).to_answer(), "-7");
+    }
+
+    #[test]
+    fn test_tuples() {
+        assert_eq!((6, 1).to_answer(), "6,1");
+        assert_eq!((1u8, "b", 'c').to_answer(), "1,b,c");
+    }
+
+    #[test]
+    fn test_grids() {
+        let grid = vec![vec!['#', '.'], vec!['.', '#']];
+        assert_eq!(grid.to_answer(), "#.\n.#");
+        assert_eq!(grid.as_slice().to_answer(), "#.\n.#");
+        assert_eq!(vec![b"#.".to_vec(), b".#".to_vec()].to_answer(), "#.\n.#");
+    }
+}
@@ -1,10 +1,67 @@
-#[derive(Debug)]
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+mod answer;
+
+pub use answer::Answer;
+
+/// The function the runner calls: the solver wrapped to take the input and parameters.
+/// Returns the formatted answer, or the error when the solver returned an `Err` or `None`.
+pub type SolverFn = fn(&SolverInput, &Params) -> Result<String, String>;
+
+/// A day's input as parsed by its generator
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// The function the runner calls to parse a day's input: the generator wrapped to
+/// return its value type-erased, or the error when it returned an `Err` or `None`
+pub type GeneratorFn = fn(&SolverInput) -> Result<Parsed, String>;
+
+/// The input handed to a solver: the contents of the input file with `\n` line endings, split into lines
+/// and, on days with a generator, the value it parsed them into
+#[derive(Clone, Copy)]
+pub struct SolverInput<'a> {
+    pub text: &'a str,
+    pub lines: &'a [&'a str],
+    pub parsed: Option<&'a (dyn Any + Send + Sync)>,
+}
+
+impl<'a> SolverInput<'a> {
+    /// `lines` are expected to be the lines of `text`
+    pub fn new(text: &'a str, lines: &'a [&'a str]) -> SolverInput<'a> {
+        SolverInput { text, lines, parsed: None }
+    }
+
+    /// The value the day's generator produced. Fails when there was no generator or it
+    /// produced a different type.
+    pub fn parsed<T: 'static>(&self) -> Result<&'a T, String> {
+        let parsed = self.parsed.ok_or("the input wasn't parsed, is there an `#[aoc_generator]` for this day?")?;
+        parsed.downcast_ref::<T>()
+            .ok_or_else(|| format!("the generator for this day doesn't produce `{}`", std::any::type_name::<T>()))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Solver {
     pub year: u16,
     pub day: u8,
     pub level: u8,
     pub name: String,
-    pub func: fn(&[&str]) -> String,
+    pub func: SolverFn,
+    pub enabled: bool,
+    pub slow: bool,
+    /// Named parameters with their default values
+    pub params: &'static [(&'static str, &'static str)],
+    /// Takes the value parsed by the day's generator instead of the input
+    pub uses_generator: bool,
+}
+
+impl Solver {
+    /// Whether the solver runs without explicitly asking for it. Disabled and slow solvers don't.
+    pub fn runs_by_default(&self) -> bool {
+        self.enabled && !self.slow
+    }
 }
 
 pub struct SolverInfo {
@@ -12,11 +69,40 @@ pub struct SolverInfo {
     pub day: u8,
     pub level: u8,
     pub name: &'static str,
-    pub func: fn(&[&str]) -> String,
+    pub func: SolverFn,
+    /// `false` keeps the solver registered but skips it unless asked for
+    pub enabled: bool,
+    /// Marks a solver that takes long to run; it's skipped unless asked for
+    pub slow: bool,
+    /// The source file the solver was registered in
+    pub file: &'static str,
+    /// Named parameters with their default values, as declared in the attribute
+    pub params: &'static [(&'static str, &'static str)],
+    /// Takes the value parsed by the day's generator instead of the input
+    pub uses_generator: bool,
 }
 
 inventory::collect!(SolverInfo);
 
+/// A function registered with `#[aoc_generator]` that parses a day's input once for all its solvers
+pub struct GeneratorInfo {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub func: GeneratorFn,
+    /// The source file the generator was registered in
+    pub file: &'static str,
+}
+
+inventory::collect!(GeneratorInfo);
+
+/// The generator registered for a day, if any
+pub fn find_generator(year: u16, day: u8) -> Option<&'static GeneratorInfo> {
+    inventory::iter::<GeneratorInfo>
+        .into_iter()
+        .find(|info| info.year == year && info.day == day)
+}
+
 pub fn discover_solvers(year: u16, day: u8) -> Vec<Solver> {
     inventory::iter::<SolverInfo>
         .into_iter()
@@ -27,11 +113,62 @@ pub fn discover_solvers(year: u16, day: u8) -> Vec<Solver> {
             level: info.level,
             name: info.name.to_string(),
             func: info.func,
+            enabled: info.enabled,
+            slow: info.slow,
+            params: info.params,
+            uses_generator: info.uses_generator,
         })
         .collect()
 }
 
-pub fn list_solvers() -> Result<(), Box<dyn std::error::Error>> {
+/// Named solver parameters, for constants that differ between the sample and the real
+/// input, like a grid size. Defaults are declared in `#[advent_of_code]` and can be
+/// overridden per input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new(defaults: &[(&str, &str)], overrides: &[(String, String)]) -> Params {
+        let mut values: BTreeMap<String, String> = defaults.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        values.extend(overrides.iter().cloned());
+
+        Params { values }
+    }
+
+    /// The value of a parameter. Panics when it's missing or can't be parsed as `T`.
+    #[track_caller]
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(value) = self.values.get(name) else {
+            panic!("solver parameter `{}` has no value", name);
+        };
+        match value.parse() {
+            Ok(value) => value,
+            Err(e) => panic!("solver parameter `{}` = `{}` is invalid: {}", name, value, e),
+        }
+    }
+}
+
+/// All `(year, day)` pairs that have registered solvers, sorted. Limited to one year if given.
+pub fn registered_days(year: Option<u16>) -> Vec<(u16, u8)> {
+    let days: std::collections::BTreeSet<(u16, u8)> = inventory::iter::<SolverInfo>
+        .into_iter()
+        .filter(|info| year.is_none_or(|y| info.year == y))
+        .map(|info| (info.year, info.day))
+        .collect();
+
+    days.into_iter().collect()
+}
+
+/// All registered solvers, sorted by year, day, level and name
+pub fn registered_solvers() -> Vec<&'static SolverInfo> {
     let mut solvers: Vec<_> = inventory::iter::<SolverInfo>
         .into_iter()
         .collect();
@@ -40,18 +177,147 @@ pub fn list_solvers() -> Result<(), Box<dyn std::error::Error>> {
         a.year.cmp(&b.year)
             .then(a.day.cmp(&b.day))
             .then(a.level.cmp(&b.level))
-            .then(a.name.cmp(&b.name))
+            .then(a.name.cmp(b.name))
     });
 
+    solvers
+}
+
+impl std::fmt::Display for SolverInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{:02}, {}, {}{}", self.year, self.day, self.level, self.name, describe_flags(self))
+    }
+}
+
+/// A problem found while validating the registered solvers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryIssue {
+    /// The same solver name is registered more than once for a year, day and level
+    Duplicate { year: u16, day: u8, level: u8, name: &'static str },
+    /// The solver's file name or folder doesn't match the year and day in its attribute
+    FileMismatch { year: u16, day: u8, name: &'static str, file: &'static str },
+    /// A day has a solver for level 2 but none for level 1
+    MissingLevel1 { year: u16, day: u8 },
+    /// More than one generator is registered for a day
+    DuplicateGenerator { year: u16, day: u8 },
+    /// A solver takes parsed input but its day has no generator
+    MissingGenerator { year: u16, day: u8, level: u8, name: &'static str },
+}
+
+impl std::fmt::Display for RegistryIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryIssue::Duplicate { year, day, level, name } =>
+                write!(f, "{}-{:02}, {}: `{}` is registered more than once", year, day, level, name),
+            RegistryIssue::FileMismatch { year, day, name, file } =>
+                write!(f, "{}-{:02}: `{}` is registered in {}", year, day, name, file),
+            RegistryIssue::MissingLevel1 { year, day } =>
+                write!(f, "{}-{:02}: has a level 2 solver but no level 1 solver", year, day),
+            RegistryIssue::DuplicateGenerator { year, day } =>
+                write!(f, "{}-{:02}: has more than one generator", year, day),
+            RegistryIssue::MissingGenerator { year, day, level, name } =>
+                write!(f, "{}-{:02}, {}: `{}` takes parsed input but there's no generator", year, day, level, name),
+        }
+    }
+}
+
+/// Validate all registered solvers and generators
+pub fn validate_registry() -> Vec<RegistryIssue> {
+    let solvers: Vec<&SolverInfo> = inventory::iter::<SolverInfo>.into_iter().collect();
+    let generators: Vec<&GeneratorInfo> = inventory::iter::<GeneratorInfo>.into_iter().collect();
+
+    let mut issues = validate_solvers(&solvers);
+    issues.extend(validate_generators(&solvers, &generators));
+    issues
+}
+
+/// Find duplicate registrations, solvers in files that don't match their
+/// attribute and days that have a level 2 solver without a level 1 solver
+pub fn validate_solvers(solvers: &[&SolverInfo]) -> Vec<RegistryIssue> {
+    let mut issues = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    let mut levels: std::collections::BTreeMap<(u16, u8), Vec<u8>> = std::collections::BTreeMap::new();
+
+    for solver in solvers {
+        if !seen.insert((solver.year, solver.day, solver.level, solver.name)) {
+            issues.push(RegistryIssue::Duplicate {
+                year: solver.year,
+                day: solver.day,
+                level: solver.level,
+                name: solver.name,
+            });
+        }
+
+        if !file_matches(solver.file, solver.year, solver.day) {
+            issues.push(RegistryIssue::FileMismatch {
+                year: solver.year,
+                day: solver.day,
+                name: solver.name,
+                file: solver.file,
+            });
+        }
+
+        levels.entry((solver.year, solver.day)).or_default().push(solver.level);
+    }
+
+    for ((year, day), levels) in levels {
+        if levels.contains(&2) && !levels.contains(&1) {
+            issues.push(RegistryIssue::MissingLevel1 { year, day });
+        }
+    }
+
+    issues
+}
+
+/// Find days with more than one generator and solvers that take parsed input on days without one
+pub fn validate_generators(solvers: &[&SolverInfo], generators: &[&GeneratorInfo]) -> Vec<RegistryIssue> {
+    let mut issues = Vec::new();
+    let mut days = std::collections::BTreeMap::new();
+    for generator in generators {
+        *days.entry((generator.year, generator.day)).or_insert(0) += 1;
+    }
+
+    for (&(year, day), &count) in &days {
+        if count > 1 {
+            issues.push(RegistryIssue::DuplicateGenerator { year, day });
+        }
+    }
     for solver in solvers {
-        println!("{}-{:02}, {}, {}", 
-            solver.year,
-            solver.day,
-            solver.level,
-            solver.name);
+        if solver.uses_generator && !days.contains_key(&(solver.year, solver.day)) {
+            issues.push(RegistryIssue::MissingGenerator {
+                year: solver.year,
+                day: solver.day,
+                level: solver.level,
+                name: solver.name,
+            });
+        }
     }
 
-    Ok(())
+    issues
+}
+
+/// Check that a file like `src/2024/09-disk-fragmenter.rs` belongs to the given year and day.
+/// Files that don't follow that naming scheme are not checked.
+fn file_matches(file: &str, year: u16, day: u8) -> bool {
+    let path = std::path::Path::new(file);
+    let file_day = path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(|stem| stem.chars().take_while(|c| c.is_ascii_digit()).collect::<String>())
+        .and_then(|digits| digits.parse::<u8>().ok());
+    let file_year = path.parent()
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.parse::<u16>().ok());
+
+    file_day.is_none_or(|d| d == day) && file_year.is_none_or(|y| y == year)
+}
+
+fn describe_flags(solver: &SolverInfo) -> &'static str {
+    match (solver.enabled, solver.slow) {
+        (false, _) => " (disabled)",
+        (true, true) => " (slow)",
+        (true, false) => "",
+    }
 }
 
 #[cfg(test)]
@@ -61,9 +327,9 @@ mod tests {
     #[test]
     fn test_solver_sorting() {
         let solvers = vec![
-            SolverInfo { year: 2024, day: 1, level: 2, name: "solve_b", func: |_| "".into() },
-            SolverInfo { year: 2024, day: 1, level: 1, name: "solve_a", func: |_| "".into() },
-            SolverInfo { year: 2023, day: 25, level: 1, name: "solve", func: |_| "".into() },
+            SolverInfo { year: 2024, day: 1, level: 2, name: "solve_b", func: |_, _| Ok("".into()), enabled: true, slow: false, file: "", params: &[], uses_generator: false },
+            SolverInfo { year: 2024, day: 1, level: 1, name: "solve_a", func: |_, _| Ok("".into()), enabled: true, slow: false, file: "", params: &[], uses_generator: false },
+            SolverInfo { year: 2023, day: 25, level: 1, name: "solve", func: |_, _| Ok("".into()), enabled: true, slow: false, file: "", params: &[], uses_generator: false },
         ];
 
         let mut sorted = solvers;
@@ -71,7 +337,7 @@ mod tests {
             a.year.cmp(&b.year)
                 .then(a.day.cmp(&b.day))
                 .then(a.level.cmp(&b.level))
-                .then(a.name.cmp(&b.name))
+                .then(a.name.cmp(b.name))
         });
 
         assert_eq!(sorted[0].year, 2023);
@@ -79,4 +345,87 @@ mod tests {
         assert_eq!(sorted[1].level, 1);
         assert_eq!(sorted[2].level, 2);
     }
-}
\ No newline at end of file
+
+    fn info(year: u16, day: u8, level: u8, name: &'static str, file: &'static str) -> SolverInfo {
+        SolverInfo { year, day, level, name, func: |_, _| Ok("".into()), enabled: true, slow: false, file, params: &[], uses_generator: false }
+    }
+
+    #[test]
+    fn test_validate_solvers() {
+        let solvers = [
+            info(2024, 9, 1, "solve_level1", "src/2024/09-disk-fragmenter.rs"),
+            info(2024, 9, 2, "solve_level2", "src/2024/09-disk-fragmenter.rs"),
+            info(2024, 9, 2, "solve_level2", "src/2024/09-disk-fragmenter-copy.rs"),
+            info(2024, 10, 2, "solve_level2", "src/2024/11-plutonian-pebbles.rs"),
+            info(2021, 1, 1, "solve_level1", "src/2024/01-sonar-sweep.rs"),
+            info(2021, 2, 1, "solve_level1", "src/helpers.rs"),
+        ];
+        let solvers: Vec<&SolverInfo> = solvers.iter().collect();
+
+        assert_eq!(validate_solvers(&solvers), vec![
+            RegistryIssue::Duplicate { year: 2024, day: 9, level: 2, name: "solve_level2" },
+            RegistryIssue::FileMismatch { year: 2024, day: 10, name: "solve_level2", file: "src/2024/11-plutonian-pebbles.rs" },
+            RegistryIssue::FileMismatch { year: 2021, day: 1, name: "solve_level1", file: "src/2024/01-sonar-sweep.rs" },
+            RegistryIssue::MissingLevel1 { year: 2024, day: 10 },
+        ]);
+    }
+
+    #[test]
+    fn test_validate_generators() {
+        let generator = |day| GeneratorInfo { year: 2024, day, name: "parse", func: |_| Ok(Box::new(())), file: "" };
+        let generators = [generator(16), generator(16), generator(6)];
+        let generators: Vec<&GeneratorInfo> = generators.iter().collect();
+        let solvers = [
+            SolverInfo { uses_generator: true, ..info(2024, 16, 1, "solve_level1", "") },
+            SolverInfo { uses_generator: true, ..info(2024, 17, 1, "solve_level1", "") },
+            info(2024, 18, 1, "solve_level1", ""),
+        ];
+        let solvers: Vec<&SolverInfo> = solvers.iter().collect();
+
+        assert_eq!(validate_generators(&solvers, &generators), vec![
+            RegistryIssue::DuplicateGenerator { year: 2024, day: 16 },
+            RegistryIssue::MissingGenerator { year: 2024, day: 17, level: 1, name: "solve_level1" },
+        ]);
+    }
+
+    #[test]
+    fn test_parsed_input() {
+        let parsed: Parsed = Box::new(vec![1u32, 2, 3]);
+        let input = SolverInput { text: "1\n2\n3\n", lines: &["1", "2", "3"], parsed: Some(parsed.as_ref()) };
+
+        assert_eq!(input.parsed::<Vec<u32>>().unwrap(), &vec![1, 2, 3]);
+        assert!(input.parsed::<String>().unwrap_err().contains("doesn't produce `alloc::string::String`"));
+        assert!(SolverInput::new("1\n", &["1"]).parsed::<Vec<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_runs_by_default() {
+        let solver = |enabled, slow| Solver {
+            year: 2024, day: 1, level: 1, name: "solve".into(), func: |_, _| Ok("".into()), enabled, slow, params: &[], uses_generator: false,
+        };
+
+        assert!(solver(true, false).runs_by_default());
+        assert!(!solver(false, false).runs_by_default());
+        assert!(!solver(true, true).runs_by_default());
+    }
+
+    #[test]
+    fn test_params_overrides_replace_defaults() {
+        let params = Params::new(&[("dim", "71"), ("drop", "1024")], &[("dim".to_string(), "7".to_string())]);
+
+        assert_eq!(params.get::<usize>("dim"), 7);
+        assert_eq!(params.get::<u32>("drop"), 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "solver parameter `dim` has no value")]
+    fn test_params_missing_value() {
+        Params::default().get::<usize>("dim");
+    }
+
+    #[test]
+    #[should_panic(expected = "solver parameter `dim` = `seven` is invalid")]
+    fn test_params_invalid_value() {
+        Params::new(&[("dim", "seven")], &[]).get::<usize>("dim");
+    }
+}
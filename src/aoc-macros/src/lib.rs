@@ -1,40 +1,116 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, ItemFn, Lit};
+use proc_macro2::Span;
+use syn::{parse_macro_input, Expr, FnArg, ItemFn, Lit, ReturnType, Type};
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
 
 /// Marks a function as an Advent of Code solver
 ///
+/// The first three arguments are the year, day and level. An optional fourth
+/// boolean argument disables the solver when `false`. The named flags `skip`
+/// and `slow` may follow; both keep the solver registered but skip it unless
+/// asked for.
+///
+/// The answer is written with `aoc_core::Answer`, so the return type must
+/// implement it. Solvers may return a `Result` or an `Option`. The runner
+/// verifies the value inside `Ok` or `Some` and reports an `Err` or `None` as
+/// an error; the error type must implement `Display`.
+///
+/// The input may be taken as `&str` (the whole file), `&[u8]` (its bytes),
+/// `&[&str]` (its lines), `&[&[u8]]` (the bytes of each line) or as a grid of
+/// `Vec<Vec<char>>` or `Vec<Vec<u8>>`. Any other reference is the value parsed
+/// by the day's generator.
+///
+/// Named parameters like `size = 71` declare constants that differ between the
+/// sample and the real input. The value is the default; a `.params` file next to
+/// an input file overrides it. Solvers with parameters take `&Params` as a
+/// second argument.
+///
 /// # Example
 /// ```ignore
 /// #[advent_of_code(2024, 1, 1)]
 /// pub fn solve_level1(input: &[&str]) -> String {
 ///     // solution implementation
 /// }
+///
+/// #[advent_of_code(2024, 1, 1, false)]
+/// pub fn alternative_solution(input: &[&str]) -> String {
+///     // skipped by default
+/// }
+///
+/// #[advent_of_code(2024, 1, 2, slow)]
+/// pub fn brute_force(input: &[&str]) -> String {
+///     // skipped by default
+/// }
+///
+/// #[advent_of_code(2024, 25, 1)]
+/// pub fn solve_level1(input: &str) -> usize {
+///     let schematics = input.split("\n\n");
+/// }
+///
+/// #[advent_of_code(2024, 18, 1, size = 71, bytes = 1024)]
+/// pub fn solve_level1(input: &[&str], params: &Params) -> String {
+///     let size: usize = params.get("size");
+/// }
 /// ```
 #[proc_macro_attribute]
 pub fn advent_of_code(args: TokenStream, input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(args with Punctuated::<Lit, Comma>::parse_terminated);
+    let args = parse_macro_input!(args with Punctuated::<Expr, Comma>::parse_terminated);
     let input_fn = parse_macro_input!(input as ItemFn);
-    
-    let (year, day, level) = parse_args(&args);
-    let fn_name = &input_fn.sig.ident;
-    let fn_vis = &input_fn.vis;
-    let fn_block = &input_fn.block;
-    let fn_return_type = &input_fn.sig.output;
 
+    let solver_args = parse_args(&args);
+    let signature = check_signature(&input_fn);
+    let (SolverArgs { year, day, level, enabled, slow, params }, signature) = match (solver_args, signature) {
+        (Ok(solver_args), Ok(signature)) => (solver_args, signature),
+        (Err(mut error), Err(signature_error)) => {
+            error.combine(signature_error);
+            return error.to_compile_error().into();
+        }
+        (Err(error), _) | (_, Err(error)) => return error.to_compile_error().into(),
+    };
+
+    let fn_name = &input_fn.sig.ident;
     let fn_runner_name = format_ident!("{}__runner__", &input_fn.sig.ident);
-    
+
+    if !params.is_empty() && !signature.takes_params {
+        return syn::Error::new_spanned(
+            &input_fn.sig.inputs,
+            "solver with named parameters must take `&Params` as its second input",
+        ).to_compile_error().into();
+    }
+    let call = if signature.takes_params {
+        quote! { #fn_name(input, params) }
+    } else {
+        quote! { { let _ = params; #fn_name(input) } }
+    };
+    let answer = match return_kind(&input_fn.sig.output) {
+        ReturnKind::Result => quote! {
+            #call.map(|answer| aoc_core::Answer::to_answer(&answer)).map_err(|error| error.to_string())
+        },
+        ReturnKind::Option => quote! {
+            #call.map(|answer| aoc_core::Answer::to_answer(&answer)).ok_or_else(|| "solver returned None".to_string())
+        },
+        ReturnKind::Value => quote! { Ok(aoc_core::Answer::to_answer(&#call)) },
+    };
+    let uses_generator = matches!(signature.input, InputKind::Parsed(_));
+    let input = match &signature.input {
+        InputKind::Raw(adapter) => adapter.clone(),
+        InputKind::Parsed(parsed) => quote! { input.parsed::<#parsed>()? },
+    };
+    let param_names = params.iter().map(|(name, _)| name);
+    let param_defaults = params.iter().map(|(_, value)| value);
+
     let expanded = quote! {
         #[doc(hidden)]
         #[allow(non_snake_case)]
-        pub fn #fn_runner_name(input: &[&str]) -> String {
-            format!("{:?}", #fn_name(input))
+        pub fn #fn_runner_name(input: &aoc_core::SolverInput, params: &aoc_core::Params) -> Result<String, String> {
+            let input = #input;
+            #answer
         }
 
         #[doc(hidden)]
-        #fn_vis fn #fn_name(input: &[&str]) #fn_return_type #fn_block
+        #input_fn
 
         inventory::submit! {
             aoc_core::SolverInfo {
@@ -43,6 +119,81 @@ pub fn advent_of_code(args: TokenStream, input: TokenStream) -> TokenStream {
                 level: #level,
                 name: stringify!(#fn_name),
                 func: #fn_runner_name,
+                enabled: #enabled,
+                slow: #slow,
+                file: file!(),
+                params: &[#((#param_names, #param_defaults)),*],
+                uses_generator: #uses_generator,
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Marks a function as the input generator of a day
+///
+/// The arguments are the year and day. The generator parses the input once;
+/// solvers of that day that take a reference to its return type get the parsed
+/// value. A generator takes the input in any of the forms a solver can, like
+/// `&str` or `&[&str]`, and like solvers, it may return a `Result` or an `Option`.
+///
+/// # Example
+/// ```ignore
+/// #[aoc_generator(2024, 16)]
+/// pub fn parse(input: &[&str]) -> Maze {
+///     // parse the maze
+/// }
+///
+/// #[advent_of_code(2024, 16, 1)]
+/// pub fn solve_level1(maze: &Maze) -> usize {
+///     // solution implementation
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn aoc_generator(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with Punctuated::<Expr, Comma>::parse_terminated);
+    let input_fn = parse_macro_input!(input as ItemFn);
+
+    let (year, day, input) = match (parse_generator_args(&args), check_generator_signature(&input_fn)) {
+        (Ok((year, day)), Ok(input)) => (year, day, input),
+        (Err(mut error), Err(signature_error)) => {
+            error.combine(signature_error);
+            return error.to_compile_error().into();
+        }
+        (Err(error), _) | (_, Err(error)) => return error.to_compile_error().into(),
+    };
+
+    let fn_name = &input_fn.sig.ident;
+    let fn_generator_name = format_ident!("{}__generator__", &input_fn.sig.ident);
+    let parsed = match return_kind(&input_fn.sig.output) {
+        ReturnKind::Result => quote! {
+            #fn_name(input).map(|parsed| Box::new(parsed) as aoc_core::Parsed).map_err(|error| error.to_string())
+        },
+        ReturnKind::Option => quote! {
+            #fn_name(input).map(|parsed| Box::new(parsed) as aoc_core::Parsed).ok_or_else(|| "generator returned None".to_string())
+        },
+        ReturnKind::Value => quote! { Ok(Box::new(#fn_name(input))) },
+    };
+
+    let expanded = quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        pub fn #fn_generator_name(input: &aoc_core::SolverInput) -> Result<aoc_core::Parsed, String> {
+            let input = #input;
+            #parsed
+        }
+
+        #[doc(hidden)]
+        #input_fn
+
+        inventory::submit! {
+            aoc_core::GeneratorInfo {
+                year: #year,
+                day: #day,
+                name: stringify!(#fn_name),
+                func: #fn_generator_name,
+                file: file!(),
             }
         }
     };
@@ -50,25 +201,292 @@ pub fn advent_of_code(args: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-fn parse_args(args: &Punctuated<Lit, Comma>) -> (u16, u8, u8) {
-    if args.len() != 3 {
-        panic!("advent_of_code attribute requires exactly 3 arguments: year, day, and level");
+fn parse_generator_args(args: &Punctuated<Expr, Comma>) -> syn::Result<(u16, u8)> {
+    if args.len() != 2 {
+        return Err(syn::Error::new(Span::call_site(), "aoc_generator attribute requires 2 arguments: year and day"));
+    }
+
+    let year = parse_number::<u16>(&args[0], "year", 2015..=u16::MAX, "2015 or later")?;
+    let day = parse_number::<u8>(&args[1], "day", 1..=25, "between 1 and 25")?;
+    Ok((year, day))
+}
+
+/// Generators take exactly one input in one of the raw forms and return the parsed
+/// value. Returns the expression adapting the input to that form.
+fn check_generator_signature(input_fn: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let inputs = &input_fn.sig.inputs;
+    let expected = "generator must take a single input of type `&str`, `&[u8]`, `&[&str]`, `&[&[u8]]`, \
+        `Vec<Vec<char>>` or `Vec<Vec<u8>>`";
+
+    let input = match inputs.first() {
+        None => return Err(syn::Error::new_spanned(&input_fn.sig.ident, expected)),
+        Some(_) if inputs.len() > 1 => return Err(syn::Error::new_spanned(inputs, expected)),
+        Some(FnArg::Typed(arg)) => match raw_input(&arg.ty) {
+            Some(input) => input,
+            None => return Err(syn::Error::new_spanned(&arg.ty, expected)),
+        },
+        Some(receiver) => return Err(syn::Error::new_spanned(receiver, expected)),
+    };
+
+    match input_fn.sig.output {
+        ReturnType::Default => Err(syn::Error::new_spanned(&input_fn.sig, "generator must return the parsed input")),
+        ReturnType::Type(..) => Ok(input),
+    }
+}
+
+struct SolverArgs {
+    year: u16,
+    day: u8,
+    level: u8,
+    enabled: bool,
+    slow: bool,
+    /// Named parameters and their default values
+    params: Vec<(String, String)>,
+}
+
+fn parse_args(args: &Punctuated<Expr, Comma>) -> syn::Result<SolverArgs> {
+    if args.len() < 3 {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "advent_of_code attribute requires at least 3 arguments: year, day, and level",
+        ));
     }
 
-    let year = match &args[0] {
-        Lit::Int(lit) => lit.base10_parse::<u16>().unwrap(),
-        _ => panic!("year must be a literal integer"),
+    let year = parse_number::<u16>(&args[0], "year", 2015..=u16::MAX, "2015 or later")?;
+    let day = parse_number::<u8>(&args[1], "day", 1..=25, "between 1 and 25")?;
+    let level = parse_number::<u8>(&args[2], "level", 1..=2, "1 or 2")?;
+
+    let mut solver_args = SolverArgs { year, day, level, enabled: true, slow: false, params: Vec::new() };
+    for (index, arg) in args.iter().enumerate().skip(3) {
+        if let Expr::Assign(assign) = arg {
+            let (name, value) = parse_param(assign)?;
+            if solver_args.params.iter().any(|(existing, _)| *existing == name) {
+                return Err(syn::Error::new_spanned(&assign.left, format!("parameter `{}` is declared twice", name)));
+            }
+            solver_args.params.push((name, value));
+            continue;
+        }
+        if !solver_args.params.is_empty() {
+            return Err(syn::Error::new_spanned(arg, "named parameters must come after the other arguments"));
+        }
+
+        match (literal(arg), flag_name(arg).as_deref()) {
+            (Some(Lit::Bool(lit)), _) if index == 3 => solver_args.enabled = lit.value,
+            (_, Some("skip")) => solver_args.enabled = false,
+            (_, Some("slow")) => solver_args.slow = true,
+            _ => return Err(syn::Error::new_spanned(
+                arg,
+                "unexpected argument, expected `true`/`false` as the fourth argument, the flags `skip` and `slow` or a named parameter",
+            )),
+        }
+    }
+
+    Ok(solver_args)
+}
+
+/// A named parameter `name = literal`. The default is kept as text and parsed by the solver.
+fn parse_param(assign: &syn::ExprAssign) -> syn::Result<(String, String)> {
+    let name = flag_name(&assign.left)
+        .ok_or_else(|| syn::Error::new_spanned(&assign.left, "parameter name must be an identifier"))?;
+
+    let value = match assign.right.as_ref() {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Str(lit) => lit.value(),
+            Lit::Char(lit) => lit.value().to_string(),
+            Lit::Int(lit) => lit.base10_digits().to_string(),
+            Lit::Float(lit) => lit.base10_digits().to_string(),
+            Lit::Bool(lit) => lit.value.to_string(),
+            lit => return Err(syn::Error::new_spanned(lit, "parameter default must be a number, string, char or bool")),
+        },
+        Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match literal(expr) {
+            Some(Lit::Int(lit)) => format!("-{}", lit.base10_digits()),
+            Some(Lit::Float(lit)) => format!("-{}", lit.base10_digits()),
+            _ => return Err(syn::Error::new_spanned(&assign.right, "parameter default must be a literal")),
+        },
+        other => return Err(syn::Error::new_spanned(other, "parameter default must be a literal")),
+    };
+
+    Ok((name, value))
+}
+
+fn parse_number<N>(expr: &Expr, name: &str, range: std::ops::RangeInclusive<N>, expected: &str) -> syn::Result<N>
+where
+    N: std::str::FromStr + PartialOrd,
+    N::Err: std::fmt::Display,
+{
+    let lit = match literal(expr) {
+        Some(Lit::Int(lit)) => lit,
+        _ => return Err(syn::Error::new_spanned(expr, format!("{} must be a literal integer", name))),
     };
 
-    let day = match &args[1] {
-        Lit::Int(lit) => lit.base10_parse::<u8>().unwrap(),
-        _ => panic!("day must be a literal integer"),
+    let number = lit.base10_parse::<N>()
+        .map_err(|_| syn::Error::new_spanned(lit, format!("{} must be {}", name, expected)))?;
+
+    if range.contains(&number) {
+        Ok(number)
+    } else {
+        Err(syn::Error::new_spanned(lit, format!("{} must be {}", name, expected)))
+    }
+}
+
+fn literal(expr: &Expr) -> Option<&Lit> {
+    match expr {
+        Expr::Lit(expr_lit) => Some(&expr_lit.lit),
+        _ => None,
+    }
+}
+
+fn flag_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(expr_path) => expr_path.path.get_ident().map(|ident| ident.to_string()),
+        _ => None,
+    }
+}
+
+enum InputKind {
+    /// The input file in some form, with the expression building it from the `SolverInput`
+    Raw(proc_macro2::TokenStream),
+    /// The type the day's generator parses the input into
+    Parsed(Type),
+}
+
+struct Signature {
+    input: InputKind,
+    takes_params: bool,
+}
+
+/// Solvers take the input in one of the raw forms or a reference to the type produced
+/// by the day's generator, optionally followed by `&Params`
+fn check_signature(input_fn: &ItemFn) -> syn::Result<Signature> {
+    let inputs = &input_fn.sig.inputs;
+    let expected = "solver must take an input of type `&str`, `&[u8]`, `&[&str]`, `&[&[u8]]`, `Vec<Vec<char>>`, \
+        `Vec<Vec<u8>>` or a reference to the generator's type, optionally followed by `&Params`";
+
+    if inputs.is_empty() {
+        return Err(syn::Error::new_spanned(&input_fn.sig.ident, expected));
+    }
+    if inputs.len() > 2 {
+        return Err(syn::Error::new_spanned(inputs, expected));
+    }
+
+    let input = match &inputs[0] {
+        FnArg::Typed(arg) => match (raw_input(&arg.ty), parsed_type(&arg.ty)) {
+            (Some(adapter), _) => InputKind::Raw(adapter),
+            (None, Some(parsed)) => InputKind::Parsed(parsed),
+            (None, None) => return Err(syn::Error::new_spanned(&arg.ty, expected)),
+        },
+        receiver => return Err(syn::Error::new_spanned(receiver, expected)),
     };
 
-    let level = match &args[2] {
-        Lit::Int(lit) => lit.base10_parse::<u8>().unwrap(),
-        _ => panic!("level must be a literal integer"),
+    let takes_params = match inputs.iter().nth(1) {
+        None => false,
+        Some(FnArg::Typed(arg)) if is_params_ref(&arg.ty) => true,
+        Some(FnArg::Typed(arg)) => return Err(syn::Error::new_spanned(&arg.ty, expected)),
+        Some(receiver) => return Err(syn::Error::new_spanned(receiver, expected)),
     };
 
-    (year, day, level)
+    Ok(Signature { input, takes_params })
+}
+
+/// The referenced type of an input like `&Maze`, which the day's generator has to produce
+fn parsed_type(ty: &Type) -> Option<Type> {
+    let Type::Reference(reference) = ty else { return None };
+    if reference.mutability.is_some() {
+        return None;
+    }
+    match reference.elem.as_ref() {
+        Type::Path(path) if path.path.is_ident("str") || is_params_ref(ty) => None,
+        elem @ (Type::Path(_) | Type::Tuple(_)) => Some(elem.clone()),
+        _ => None,
+    }
+}
+
+fn is_params_ref(ty: &Type) -> bool {
+    let Type::Reference(reference) = ty else { return false };
+    if reference.mutability.is_some() {
+        return false;
+    }
+    matches!(reference.elem.as_ref(), Type::Path(path)
+        if path.qself.is_none() && path.path.segments.last().is_some_and(|segment| segment.ident == "Params"))
+}
+
+/// The expression turning the `SolverInput` named `input` into the form the function
+/// takes, when it takes the input file rather than a generator's value
+fn raw_input(ty: &Type) -> Option<proc_macro2::TokenStream> {
+    if let Some(elem) = shared_ref(ty) {
+        if is_named(elem, "str") {
+            return Some(quote! { input.text });
+        }
+        let element = slice_of(elem)?;
+        if is_named(element, "u8") {
+            return Some(quote! { input.text.as_bytes() });
+        }
+        let line = shared_ref(element)?;
+        if is_named(line, "str") {
+            return Some(quote! { input.lines });
+        }
+        if slice_of(line).is_some_and(|byte| is_named(byte, "u8")) {
+            return Some(quote! {
+                &input.lines.iter().map(|line| line.as_bytes()).collect::<Vec<&[u8]>>()
+            });
+        }
+        return None;
+    }
+
+    let cell = vec_of(vec_of(ty)?)?;
+    if is_named(cell, "char") {
+        Some(quote! { input.lines.iter().map(|line| line.chars().collect()).collect::<Vec<Vec<char>>>() })
+    } else if is_named(cell, "u8") {
+        Some(quote! { input.lines.iter().map(|line| line.as_bytes().to_vec()).collect::<Vec<Vec<u8>>>() })
+    } else {
+        None
+    }
+}
+
+/// The referenced type of a shared reference like `&'a T`
+fn shared_ref(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_none() => Some(&reference.elem),
+        _ => None,
+    }
+}
+
+fn slice_of(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Slice(slice) => Some(&slice.elem),
+        _ => None,
+    }
+}
+
+/// The element type of a `Vec<T>`
+fn vec_of(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last().filter(|segment| path.qself.is_none() && segment.ident == "Vec")?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(element)) if args.args.len() == 1 => Some(element),
+        _ => None,
+    }
+}
+
+fn is_named(ty: &Type, name: &str) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(name))
+}
+
+enum ReturnKind {
+    Result,
+    Option,
+    Value,
+}
+
+/// Recognise `Result` and `Option` by the last segment of the path, so aliases like
+/// `io::Result<T>` count as well
+fn return_kind(output: &ReturnType) -> ReturnKind {
+    let ReturnType::Type(_, ty) = output else { return ReturnKind::Value };
+    let Type::Path(path) = ty.as_ref() else { return ReturnKind::Value };
+    match path.path.segments.last().map(|segment| segment.ident.to_string()).as_deref() {
+        Some("Result") => ReturnKind::Result,
+        Some("Option") => ReturnKind::Option,
+        _ => ReturnKind::Value,
+    }
 }
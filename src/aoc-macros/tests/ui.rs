@@ -0,0 +1,6 @@
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/valid-*.rs");
+    cases.compile_fail("tests/ui/invalid-*.rs");
+}
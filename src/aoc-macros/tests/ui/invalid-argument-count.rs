@@ -0,0 +1,8 @@
+use aoc_macros::advent_of_code;
+
+#[advent_of_code(2024, 1)]
+pub fn solve_level1(input: &[&str]) -> usize {
+    input.len()
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use aoc_macros::aoc_generator;
+
+#[aoc_generator(2024)]
+pub fn parse_without_day(input: &[&str]) -> usize {
+    input.len()
+}
+
+#[aoc_generator(2024, 16)]
+pub fn parse_string(input: String) -> usize {
+    input.len()
+}
+
+#[aoc_generator(2024, 17)]
+pub fn parse_nothing(input: &[&str]) {
+    let _ = input;
+}
+
+fn main() {}
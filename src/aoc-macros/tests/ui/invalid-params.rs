@@ -0,0 +1,23 @@
+use aoc_macros::advent_of_code;
+
+#[advent_of_code(2024, 18, 1, size = 71)]
+pub fn solve_without_params(input: &[&str]) -> usize {
+    input.len()
+}
+
+#[advent_of_code(2024, 18, 2, size = 71, size = 7)]
+pub fn solve_with_duplicate(input: &[&str], params: &aoc_core::Params) -> usize {
+    input.len() + params.get::<usize>("size")
+}
+
+#[advent_of_code(2024, 18, 2, size = 71, slow)]
+pub fn solve_with_late_flag(input: &[&str], params: &aoc_core::Params) -> usize {
+    input.len() + params.get::<usize>("size")
+}
+
+#[advent_of_code(2024, 18, 2, size = input)]
+pub fn solve_with_expression(input: &[&str], params: &aoc_core::Params) -> usize {
+    input.len() + params.get::<usize>("size")
+}
+
+fn main() {}
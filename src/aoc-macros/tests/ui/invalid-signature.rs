@@ -0,0 +1,18 @@
+use aoc_macros::advent_of_code;
+
+#[advent_of_code(2024, 1, 1)]
+pub fn solve_level1(input: String) -> usize {
+    input.len()
+}
+
+#[advent_of_code(2024, 1, 2)]
+pub fn solve_level2(input: &[&str], extra: usize) -> usize {
+    input.len() + extra
+}
+
+#[advent_of_code(2024, 2, 1)]
+pub fn solve_without_input() -> usize {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use aoc_core::Params;
+use aoc_macros::{advent_of_code, aoc_generator};
+
+pub struct Maze {
+    walls: Vec<Vec<bool>>,
+}
+
+#[aoc_generator(2024, 16)]
+pub fn parse(input: &[&str]) -> Maze {
+    Maze { walls: input.iter().map(|line| line.chars().map(|c| c == '#').collect()).collect() }
+}
+
+#[advent_of_code(2024, 16, 1)]
+pub fn solve_level1(maze: &Maze) -> usize {
+    maze.walls.len()
+}
+
+#[advent_of_code(2024, 16, 2, start = 0)]
+pub fn solve_level2(maze: &Maze, params: &Params) -> usize {
+    maze.walls.len() + params.get::<usize>("start")
+}
+
+#[aoc_generator(2024, 17)]
+pub fn parse_numbers(input: &[&str]) -> Result<Vec<u64>, std::num::ParseIntError> {
+    input.iter().map(|line| line.parse()).collect()
+}
+
+#[advent_of_code(2024, 17, 1)]
+pub fn solve_numbers(numbers: &Vec<u64>) -> u64 {
+    numbers.iter().sum()
+}
+
+#[aoc_generator(2024, 18)]
+pub fn parse_first(input: &[&str]) -> Option<(usize, usize)> {
+    input.first().map(|line| (line.len(), 0))
+}
+
+#[advent_of_code(2024, 18, 1)]
+pub fn solve_first(first: &(usize, usize)) -> usize {
+    first.0 + first.1
+}
+
+fn main() {}
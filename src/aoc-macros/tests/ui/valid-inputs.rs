@@ -0,0 +1,55 @@
+use aoc_core::Params;
+use aoc_macros::{advent_of_code, aoc_generator};
+
+#[advent_of_code(2024, 1, 1)]
+pub fn solve_text(input: &str) -> usize {
+    input.split("\n\n").count()
+}
+
+#[advent_of_code(2024, 1, 2)]
+pub fn solve_bytes(input: &[u8]) -> usize {
+    input.iter().filter(|&&byte| byte == b'#').count()
+}
+
+#[advent_of_code(2024, 2, 1)]
+pub fn solve_lines<'a>(input: &'a [&'a str]) -> usize {
+    input.len()
+}
+
+#[advent_of_code(2024, 2, 2)]
+pub fn solve_byte_lines(input: &[&[u8]]) -> u8 {
+    input[0][0]
+}
+
+#[advent_of_code(2024, 3, 1)]
+pub fn solve_char_grid(mut grid: Vec<Vec<char>>) -> Vec<Vec<char>> {
+    grid.reverse();
+    grid
+}
+
+#[advent_of_code(2024, 3, 2, size = 7)]
+pub fn solve_byte_grid(grid: Vec<Vec<u8>>, params: &Params) -> usize {
+    grid.len() * params.get::<usize>("size")
+}
+
+#[aoc_generator(2024, 4)]
+pub fn parse_blocks(input: &str) -> Vec<String> {
+    input.split("\n\n").map(String::from).collect()
+}
+
+#[advent_of_code(2024, 4, 1)]
+pub fn solve_blocks(blocks: &Vec<String>) -> usize {
+    blocks.len()
+}
+
+#[aoc_generator(2024, 5)]
+pub fn parse_grid(grid: Vec<Vec<u8>>) -> Vec<Vec<bool>> {
+    grid.into_iter().map(|row| row.into_iter().map(|cell| cell == b'#').collect()).collect()
+}
+
+#[advent_of_code(2024, 5, 1)]
+pub fn solve_grid(grid: &Vec<Vec<bool>>) -> usize {
+    grid.len()
+}
+
+fn main() {}
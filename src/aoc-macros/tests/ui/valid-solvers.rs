@@ -0,0 +1,67 @@
+use aoc_core::{Answer, Params};
+use aoc_macros::advent_of_code;
+
+#[advent_of_code(2024, 1, 1)]
+pub fn solve_level1(input: &[&str]) -> usize {
+    input.len()
+}
+
+#[advent_of_code(2024, 1, 2, false)]
+pub fn solve_level2<'a>(lines: &[&'a str]) -> usize {
+    lines.len()
+}
+
+#[advent_of_code(2024, 1, 2, slow)]
+pub fn solve_level2_slowly(input: &[&str]) -> usize {
+    input.len()
+}
+
+#[advent_of_code(2024, 18, 1, size = 71, bytes = 1024, offset = -1, name = "ram")]
+pub fn solve_with_params(input: &[&str], params: &Params) -> usize {
+    input.len() + params.get::<usize>("size")
+}
+
+#[advent_of_code(2024, 18, 2)]
+pub fn solve_with_overrides_only(input: &[&str], params: &aoc_core::Params) -> usize {
+    input.len() + params.get::<usize>("size")
+}
+
+#[advent_of_code(2024, 2, 1)]
+pub fn solve_with_result(input: &[&str]) -> Result<usize, String> {
+    input.first().map(|line| line.len()).ok_or_else(|| "empty input".to_string())
+}
+
+#[advent_of_code(2024, 2, 2)]
+pub fn solve_with_io_result(input: &[&str]) -> std::io::Result<usize> {
+    Ok(input.len())
+}
+
+#[advent_of_code(2024, 3, 1)]
+pub fn solve_with_option(input: &[&str]) -> Option<usize> {
+    input.first().map(|line| line.len())
+}
+
+#[advent_of_code(2024, 3, 2)]
+pub fn solve_with_parse_error(input: &[&str]) -> Result<u64, std::num::ParseIntError> {
+    input.iter().map(|line| line.parse::<u64>()).sum()
+}
+
+pub struct Position(usize, usize);
+
+impl Answer for Position {
+    fn to_answer(&self) -> String {
+        format!("{},{}", self.0, self.1)
+    }
+}
+
+#[advent_of_code(2024, 4, 1)]
+pub fn solve_with_custom_answer(input: &[&str]) -> Position {
+    Position(input.len(), 0)
+}
+
+#[advent_of_code(2024, 4, 2)]
+pub fn solve_with_string(input: &[&str]) -> String {
+    input.join(",")
+}
+
+fn main() {}
@@ -1,20 +1,34 @@
 use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
 use reqwest::blocking::Client;
 use scraper::{Html, Selector};
+use crate::ledger::{self, Ledger, SubmissionRecord};
 
 #[cfg_attr(test, mockall::automock)]
 pub trait AdventOfCodeClient {
     fn get_problem_statement(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>>;
     fn extract_problem_name(&self, html: &str) -> Result<String, Box<dyn std::error::Error>>;
     fn get_problem_input(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>>;
+    /// The answers already accepted for a day, in level order, as shown on the day page
+    fn get_accepted_answers(&self, year: u16, day: u8) -> Result<Vec<String>, Box<dyn std::error::Error>>;
+    /// Submit an answer for the given level and return the server's verdict as plain text
+    fn submit_answer(&self, year: u16, day: u8, level: u8, answer: &str) -> Result<String, Box<dyn std::error::Error>>;
 }
 
+const ADVENT_OF_CODE_URL: &str = "https://adventofcode.com";
+
 pub struct HttpAdventOfCodeClient {
     client: Client,
+    base_url: String,
 }
 
 impl HttpAdventOfCodeClient {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_base_url(ADVENT_OF_CODE_URL)
+    }
+
+    /// Create a client that talks to the given server instead of adventofcode.com
+    pub fn with_base_url(base_url: &str) -> Result<Self, Box<dyn std::error::Error>> {
         let client = if let Some(session) = get_session_cookie() {
             let cookie = format!("session={}", session);
             Client::builder()
@@ -28,13 +42,13 @@ impl HttpAdventOfCodeClient {
             Client::builder().build()?
         };
 
-        Ok(Self { client })
+        Ok(Self { client, base_url: base_url.trim_end_matches('/').to_string() })
     }
 }
 
 impl AdventOfCodeClient for HttpAdventOfCodeClient {
     fn get_problem_statement(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
-        let url = format!("https://adventofcode.com/{}/day/{}", year, day);
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
         let response = self.client.get(&url).send()?;
         let html = response.text()?;
         
@@ -67,10 +81,106 @@ impl AdventOfCodeClient for HttpAdventOfCodeClient {
     }
 
     fn get_problem_input(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
-        let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
         let response = self.client.get(&url).send()?;
         Ok(response.text()?)
     }
+
+    fn get_accepted_answers(&self, year: u16, day: u8) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let response = self.client.get(&url).send()?;
+        let html = response.text()?;
+
+        Ok(extract_accepted_answers(&html))
+    }
+
+    fn submit_answer(&self, year: u16, day: u8, level: u8, answer: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = level.to_string();
+        let response = self.client
+            .post(&url)
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()?;
+        let html = response.text()?;
+
+        extract_article_text(&html)
+            .ok_or_else(|| "Could not find a response message in the server's reply".into())
+    }
+}
+
+/// Find the `<p>Your puzzle answer was <code>...</code></p>` paragraphs of a day page
+fn extract_accepted_answers(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let paragraph_selector = Selector::parse("main > p").unwrap();
+    let code_selector = Selector::parse("code").unwrap();
+
+    document.select(&paragraph_selector)
+        .filter(|p| p.text().collect::<String>().trim_start().starts_with("Your puzzle answer was"))
+        .filter_map(|p| p.select(&code_selector).next())
+        .map(|code| code.text().collect::<String>().trim().to_string())
+        .collect()
+}
+
+/// Render the `<article>` elements of a page as plain text, one paragraph per line
+fn extract_article_text(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse("article").unwrap();
+
+    let paragraphs: Vec<String> = document.select(&selector)
+        .map(|article| article.text().collect::<String>())
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty())
+        .collect();
+
+    if paragraphs.is_empty() {
+        None
+    } else {
+        Some(paragraphs.join("\n"))
+    }
+}
+
+/// Wraps another client and keeps a per-year ledger of every submission.
+///
+/// Answers that were rejected before, or that fall outside bounds established
+/// by earlier "too high" / "too low" responses, are refused without contacting
+/// the server.
+pub struct LedgerClient<C: AdventOfCodeClient> {
+    inner: C,
+    input_dir: PathBuf,
+}
+
+impl<C: AdventOfCodeClient> LedgerClient<C> {
+    pub fn new(inner: C, input_dir: &Path) -> Self {
+        Self { inner, input_dir: input_dir.to_path_buf() }
+    }
+}
+
+impl<C: AdventOfCodeClient> AdventOfCodeClient for LedgerClient<C> {
+    fn get_problem_statement(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+        self.inner.get_problem_statement(year, day)
+    }
+
+    fn extract_problem_name(&self, html: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.inner.extract_problem_name(html)
+    }
+
+    fn get_problem_input(&self, year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+        self.inner.get_problem_input(year, day)
+    }
+
+    fn get_accepted_answers(&self, year: u16, day: u8) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.inner.get_accepted_answers(year, day)
+    }
+
+    fn submit_answer(&self, year: u16, day: u8, level: u8, answer: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let ledger = Ledger::for_year(&self.input_dir, year);
+        ledger::check_answer(&ledger.records()?, day, level, answer)?;
+
+        let response = self.inner.submit_answer(year, day, level, answer)?;
+        ledger.append(&SubmissionRecord::from_response(day, level, answer, &response))?;
+
+        Ok(response)
+    }
 }
 
 fn get_session_cookie() -> Option<String> {
@@ -82,6 +192,9 @@ fn get_session_cookie() -> Option<String> {
 pub struct FakeClient {
     problem_statement: String,
     problem_name: String,
+    submit_response: String,
+    accepted_answers: Vec<String>,
+    submissions: std::cell::RefCell<Vec<(u16, u8, u8, String)>>,
 }
 
 #[cfg(test)]
@@ -90,8 +203,26 @@ impl FakeClient {
         Self {
             problem_statement: problem_statement.to_string(),
             problem_name: problem_name.to_string(),
+            submit_response: "That's the right answer!".to_string(),
+            accepted_answers: Vec::new(),
+            submissions: std::cell::RefCell::new(Vec::new()),
         }
     }
+
+    pub fn with_submit_response(mut self, submit_response: &str) -> Self {
+        self.submit_response = submit_response.to_string();
+        self
+    }
+
+    pub fn with_accepted_answers(mut self, accepted_answers: &[&str]) -> Self {
+        self.accepted_answers = accepted_answers.iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// All answers submitted through this client as `(year, day, level, answer)`
+    pub fn submissions(&self) -> Vec<(u16, u8, u8, String)> {
+        self.submissions.borrow().clone()
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +238,15 @@ impl AdventOfCodeClient for FakeClient {
     fn get_problem_input(&self, _year: u16, _day: u8) -> Result<String, Box<dyn std::error::Error>> {
         Ok("fake input".to_string())
     }
+
+    fn get_accepted_answers(&self, _year: u16, _day: u8) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        Ok(self.accepted_answers.clone())
+    }
+
+    fn submit_answer(&self, year: u16, day: u8, level: u8, answer: &str) -> Result<String, Box<dyn std::error::Error>> {
+        self.submissions.borrow_mut().push((year, day, level, answer.to_string()));
+        Ok(self.submit_response.clone())
+    }
 }
 
 #[cfg(test)]
@@ -126,4 +266,88 @@ mod tests {
         assert_eq!(client.get_problem_statement(2024, 1).unwrap(), "test html");
         assert_eq!(client.extract_problem_name("ignored").unwrap(), "Test Problem");
     }
+
+    #[test]
+    fn test_extract_article_text() {
+        let html = r#"<html><body><main><article><p>That's not the right answer; your answer is too high.
+            Please wait one minute before trying again. <a href="/2024/day/1">[Return to Day 1]</a></p></article></main></body></html>"#;
+        assert_eq!(
+            extract_article_text(html).unwrap(),
+            "That's not the right answer; your answer is too high. Please wait one minute before trying again. [Return to Day 1]"
+        );
+        assert!(extract_article_text("<html><body></body></html>").is_none());
+    }
+
+    #[test]
+    fn test_extract_accepted_answers() {
+        let html = r#"<html><body><main>
+            <article class="day-desc"><h2>--- Day 1: Test Problem ---</h2><p>Example <code>42</code></p></article>
+            <p>Your puzzle answer was <code>1189304</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article>
+            <p>Your puzzle answer was <code>24349736</code>.</p>
+            <p class="day-success">Both parts of this puzzle are complete! They provide two gold stars: **</p>
+            </main></body></html>"#;
+        assert_eq!(extract_accepted_answers(html), vec!["1189304", "24349736"]);
+        assert!(extract_accepted_answers("<html><body><main></main></body></html>").is_empty());
+    }
+
+    #[test]
+    fn test_submit_answer_posts_form() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !String::from_utf8_lossy(&request).contains("answer=") {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let body = "<html><body><main><article><p>That's the right answer!</p></article></main></body></html>";
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let client = HttpAdventOfCodeClient::with_base_url(&format!("http://{}", address)).unwrap();
+        let verdict = client.submit_answer(2024, 5, 2, "42").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(verdict, "That's the right answer!");
+        assert!(request.starts_with("POST /2024/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn test_fake_client_records_submissions() {
+        let client = FakeClient::new("test html", "Test Problem")
+            .with_submit_response("That's not the right answer.");
+        assert_eq!(client.submit_answer(2024, 1, 1, "42").unwrap(), "That's not the right answer.");
+        assert_eq!(client.submissions(), vec![(2024, 1, 1, "42".to_string())]);
+    }
+
+    #[test]
+    fn test_ledger_client_refuses_rejected_answers() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let client = LedgerClient::new(
+            FakeClient::new("test html", "Test Problem")
+                .with_submit_response("That's not the right answer; your answer is too low."),
+            temp.path(),
+        );
+
+        assert!(client.submit_answer(2024, 1, 1, "42").is_ok());
+        assert!(client.submit_answer(2024, 1, 1, "42").is_err());
+        assert!(client.submit_answer(2024, 1, 1, "41").is_err());
+        assert!(client.submit_answer(2024, 1, 1, "43").is_ok());
+
+        assert_eq!(client.inner.submissions(), vec![
+            (2024, 1, 1, "42".to_string()),
+            (2024, 1, 1, "43".to_string()),
+        ]);
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,295 @@
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use aoc_core::{Params, Solver, SolverInput};
+use crate::bench_history::{self, BenchHistory, BenchRecord};
+use crate::run::{self, format_duration, ParseTime, RunConfig, SharedParse};
+
+// Public Interface
+// ---------------
+
+#[derive(Debug)]
+pub struct BenchConfig {
+    pub run: RunConfig,
+    /// How long to run each solver before measuring
+    pub warmup_time: Duration,
+    /// How long to keep taking samples of each solver
+    pub measure_time: Duration,
+    /// Compare against the previously stored results
+    pub compare: bool,
+    /// How many percent slower the median may get before it counts as a regression
+    pub threshold_percent: f64,
+}
+
+/// Handle the bench command: run each selected solver many times and report statistics.
+///
+/// The input is parsed once, outside of the measurements. Results are stored per solver and
+/// commit. A solver that panics or returns an error is reported and skipped, and makes the
+/// command fail once the other solvers are done. When comparing, fails if any solver regressed.
+pub fn handle(config: BenchConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let run = &config.run;
+    run.check_arguments()?;
+    let (year, day) = run::get_year_and_day(run.year, run.day)?;
+    let input = run::load_input(run, year, day)?;
+    let lines: Vec<&str> = input.text.lines().collect();
+    let unparsed = SolverInput::new(&input.text, &lines);
+
+    let solvers = run::discover_solvers(year, day)?;
+    run::check_params(&input.params, &solvers, &input.name)?;
+    let filtered_solvers = run::filter_solvers(solvers, run.level, run.solver.as_deref(), run.include_disabled);
+
+    let parse = SharedParse::new(year, day);
+    if filtered_solvers.iter().any(|solver| solver.uses_generator) {
+        match parse.get(&unparsed) {
+            (Ok(_), ParseTime::Parsed(duration)) => println!("Parsed input in {}\n", format_duration(duration)),
+            (Ok(_), ParseTime::Cached) => {}
+            (Err(failure), _) => return Err(format!("Could not parse the input: {}", failure).into()),
+        }
+    }
+
+    let history = BenchHistory::for_year(Path::new("input"), year);
+    let commit = bench_history::current_commit();
+    let mut crashed = Vec::new();
+    let mut regressions = Vec::new();
+
+    for solver in filtered_solvers {
+        println!(
+            "Benchmarking solver for {}-12-{:02}, level {}: {}",
+            solver.year, solver.day, solver.level, solver.name
+        );
+        let params = Params::new(solver.params, &input.params);
+        let solver_input = SolverInput {
+            parsed: if solver.uses_generator { parse.get(&unparsed).0.ok() } else { None },
+            ..unparsed
+        };
+        let samples = first_answer(&solver, &solver_input, &params)
+            .and_then(|answer| {
+                println!("{}", answer);
+                let measured = run::catch_panic(|| measure(&solver, &solver_input, &params, config.warmup_time, config.measure_time));
+                measured.0.map_err(|panic| format!("PANICKED! {}", panic))
+            });
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(failure) => {
+                println!("{}\nNothing benchmarked\n", failure);
+                crashed.push(solver.name.clone());
+                continue;
+            }
+        };
+        let statistics = Statistics::from_samples(&samples);
+        print!("{}", format_statistics(&statistics));
+
+        if config.compare && compare_with_history(&history, &solver, &input.name, &statistics, config.threshold_percent)? {
+            regressions.push(solver.name.clone());
+        }
+
+        history.append(&BenchRecord {
+            timestamp: time::OffsetDateTime::now_utc().unix_timestamp(),
+            commit: commit.clone(),
+            day: solver.day,
+            level: solver.level,
+            name: solver.name.clone(),
+            input: input.name.clone(),
+            statistics,
+        })?;
+        println!();
+    }
+
+    let mut failures = Vec::new();
+    if !crashed.is_empty() {
+        failures.push(format!("{} solver(s) panicked or returned an error: {}", crashed.len(), crashed.join(", ")));
+    }
+    if !regressions.is_empty() {
+        failures.push(format!(
+            "{} solver(s) regressed by more than {}%: {}",
+            regressions.len(),
+            config.threshold_percent,
+            regressions.join(", ")
+        ));
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+/// Run the solver once for the answer to show. A panic or error is returned as the line
+/// to report instead, the same way `run` reports it.
+fn first_answer(solver: &Solver, input: &SolverInput, params: &Params) -> Result<String, String> {
+    match run::catch_panic(|| (solver.func)(input, params)).0 {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err(format!("ERROR! {}", error)),
+        Err(panic) => Err(format!("PANICKED! {}", panic)),
+    }
+}
+
+/// Print how the median changed since the last stored run. Returns whether the solver regressed.
+fn compare_with_history(
+    history: &BenchHistory,
+    solver: &Solver,
+    input_name: &str,
+    statistics: &Statistics,
+    threshold_percent: f64,
+) -> std::io::Result<bool> {
+    let Some(previous) = history.last_for(solver.day, solver.level, &solver.name, input_name)? else {
+        println!("No previous results to compare with");
+        return Ok(false);
+    };
+
+    let comparison = bench_history::compare(&previous.statistics, statistics, threshold_percent);
+    println!(
+        "Median was {} at {}: {:+.1}%{}",
+        format_duration(previous.statistics.median),
+        previous.commit,
+        comparison.delta_percent,
+        if comparison.regressed { " REGRESSED!" } else { "" }
+    );
+
+    Ok(comparison.regressed)
+}
+
+// Measuring
+// ---------
+
+/// The most samples taken per solver, so very fast solvers don't collect millions of them
+const MAX_SAMPLES: usize = 100_000;
+
+/// Run the solver repeatedly on the already parsed input: first for the warm-up
+/// time, then taking samples until the measure time is up. At least one sample is taken.
+fn measure(solver: &Solver, input: &SolverInput, params: &Params, warmup_time: Duration, measure_time: Duration) -> Vec<Duration> {
+    let warmup_start = Instant::now();
+    while warmup_start.elapsed() < warmup_time {
+        let _ = black_box((solver.func)(black_box(input), params));
+    }
+
+    let mut samples = Vec::new();
+    let measure_start = Instant::now();
+    while samples.is_empty() || (measure_start.elapsed() < measure_time && samples.len() < MAX_SAMPLES) {
+        let start = Instant::now();
+        let _ = black_box((solver.func)(black_box(input), params));
+        samples.push(start.elapsed());
+    }
+
+    samples
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Statistics {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Statistics {
+    fn from_samples(samples: &[Duration]) -> Statistics {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count as f64;
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+        let p95_rank = (count as f64 * 0.95).ceil() as usize;
+
+        Statistics {
+            samples: count,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            p95: sorted[p95_rank.max(1) - 1],
+        }
+    }
+}
+
+fn format_statistics(statistics: &Statistics) -> String {
+    format!(
+        "Samples: {}\nMin:     {}\nMedian:  {}\nMean:    {}\nStddev:  {}\nP95:     {}\n",
+        statistics.samples,
+        format_duration(statistics.min),
+        format_duration(statistics.median),
+        format_duration(statistics.mean),
+        format_duration(statistics.stddev),
+        format_duration(statistics.p95)
+    )
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_statistics() {
+        let statistics = Statistics::from_samples(&micros(&[40, 10, 30, 20, 50, 10, 20, 30, 40, 50]));
+
+        assert_eq!(statistics, Statistics {
+            samples: 10,
+            min: Duration::from_micros(10),
+            median: Duration::from_micros(30),
+            mean: Duration::from_micros(30),
+            stddev: Duration::from_nanos(14142),
+            p95: Duration::from_micros(50),
+        });
+    }
+
+    #[test]
+    fn test_statistics_of_single_sample() {
+        let statistics = Statistics::from_samples(&micros(&[25]));
+
+        assert_eq!(statistics.min, Duration::from_micros(25));
+        assert_eq!(statistics.median, Duration::from_micros(25));
+        assert_eq!(statistics.p95, Duration::from_micros(25));
+        assert_eq!(statistics.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_measure_takes_at_least_one_sample() {
+        let solver = Solver {
+            year: 2024, day: 1, level: 1, name: "solve".into(), func: |input, _| Ok(input.lines.len().to_string()),
+            enabled: true, slow: false, params: &[], uses_generator: false,
+        };
+
+        let input = SolverInput::new("a\nb\n", &["a", "b"]);
+        let samples = measure(&solver, &input, &Params::default(), Duration::ZERO, Duration::ZERO);
+        assert_eq!(samples.len(), 1);
+
+        let samples = measure(&solver, &input, &Params::default(), Duration::ZERO, Duration::from_millis(5));
+        assert!(samples.len() > 1);
+    }
+
+    #[test]
+    fn test_first_answer_catches_panics_and_errors() {
+        let solver = |func| Solver {
+            year: 2024, day: 1, level: 1, name: "solve".into(), func, enabled: true, slow: false, params: &[], uses_generator: false,
+        };
+        let input = SolverInput::new("a\nb\n", &["a", "b"]);
+
+        let answer = first_answer(&solver(|input, _| Ok(input.lines.len().to_string())), &input, &Params::default());
+        assert_eq!(answer, Ok("2".to_string()));
+
+        let error = first_answer(&solver(|_, _| Err("invalid digit".to_string())), &input, &Params::default());
+        assert_eq!(error, Err("ERROR! invalid digit".to_string()));
+
+        let panic = first_answer(&solver(|_, _| todo!()), &input, &Params::default()).unwrap_err();
+        assert!(panic.starts_with("PANICKED! at "), "{}", panic);
+        assert!(panic.ends_with(": not yet implemented"), "{}", panic);
+    }
+}
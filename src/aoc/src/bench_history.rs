@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::bench::Statistics;
+use crate::tsv;
+
+// Records
+// -------
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "BenchRow", from = "BenchRow")]
+pub struct BenchRecord {
+    pub timestamp: i64,
+    pub commit: String,
+    pub day: u8,
+    pub level: u8,
+    pub name: String,
+    /// File name of the input the solver ran on, so sample and real runs aren't compared
+    pub input: String,
+    pub statistics: Statistics,
+}
+
+/// A record as stored in the history file: flat, with the durations in nanoseconds
+#[derive(Serialize, Deserialize)]
+struct BenchRow {
+    timestamp: i64,
+    commit: String,
+    day: u8,
+    level: u8,
+    name: String,
+    input: String,
+    samples: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+    p95_ns: u64,
+}
+
+impl From<BenchRecord> for BenchRow {
+    fn from(record: BenchRecord) -> BenchRow {
+        let s = record.statistics;
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        BenchRow {
+            timestamp: record.timestamp,
+            commit: record.commit,
+            day: record.day,
+            level: record.level,
+            name: record.name,
+            input: record.input,
+            samples: s.samples,
+            min_ns: nanos(s.min),
+            median_ns: nanos(s.median),
+            mean_ns: nanos(s.mean),
+            stddev_ns: nanos(s.stddev),
+            p95_ns: nanos(s.p95),
+        }
+    }
+}
+
+impl From<BenchRow> for BenchRecord {
+    fn from(row: BenchRow) -> BenchRecord {
+        BenchRecord {
+            timestamp: row.timestamp,
+            commit: row.commit,
+            day: row.day,
+            level: row.level,
+            name: row.name,
+            input: row.input,
+            statistics: Statistics {
+                samples: row.samples,
+                min: Duration::from_nanos(row.min_ns),
+                median: Duration::from_nanos(row.median_ns),
+                mean: Duration::from_nanos(row.mean_ns),
+                stddev: Duration::from_nanos(row.stddev_ns),
+                p95: Duration::from_nanos(row.p95_ns),
+            },
+        }
+    }
+}
+
+// History
+// -------
+
+/// The benchmark results of a single year, stored in `input/<year>/benchmarks.tsv`
+pub struct BenchHistory {
+    path: PathBuf,
+}
+
+impl BenchHistory {
+    pub fn for_year(input_dir: &Path, year: u16) -> BenchHistory {
+        BenchHistory {
+            path: input_dir.join(year.to_string()).join("benchmarks.tsv"),
+        }
+    }
+
+    /// All stored results. A missing history file means nothing was benchmarked yet.
+    pub fn records(&self) -> std::io::Result<Vec<BenchRecord>> {
+        tsv::read(&self.path)
+    }
+
+    /// The most recently stored result for a solver on the given input
+    pub fn last_for(&self, day: u8, level: u8, name: &str, input: &str) -> std::io::Result<Option<BenchRecord>> {
+        Ok(self.records()?
+            .into_iter()
+            .rev()
+            .find(|r| r.day == day && r.level == level && r.name == name && r.input == input))
+    }
+
+    pub fn append(&self, record: &BenchRecord) -> std::io::Result<()> {
+        tsv::append(&self.path, record)
+    }
+}
+
+/// The current git commit, marked `-dirty` when there are uncommitted changes
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+// Comparison
+// ----------
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// Change of the median runtime in percent; positive is slower
+    pub delta_percent: f64,
+    pub regressed: bool,
+}
+
+/// Compare median runtimes. A solver regressed when it got slower by more than `threshold_percent`.
+pub fn compare(previous: &Statistics, current: &Statistics, threshold_percent: f64) -> Comparison {
+    let previous_nanos = previous.median.as_nanos() as f64;
+    let current_nanos = current.median.as_nanos() as f64;
+    let delta_percent = if previous_nanos == 0.0 {
+        0.0
+    } else {
+        (current_nanos - previous_nanos) / previous_nanos * 100.0
+    };
+
+    Comparison {
+        delta_percent,
+        regressed: delta_percent > threshold_percent,
+    }
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn statistics(median_micros: u64) -> Statistics {
+        Statistics {
+            samples: 100,
+            min: Duration::from_micros(median_micros / 2),
+            median: Duration::from_micros(median_micros),
+            mean: Duration::from_micros(median_micros),
+            stddev: Duration::from_nanos(1234),
+            p95: Duration::from_micros(median_micros * 2),
+        }
+    }
+
+    fn record(commit: &str, name: &str, median_micros: u64) -> BenchRecord {
+        BenchRecord {
+            timestamp: 1733011200,
+            commit: commit.to_string(),
+            day: 9,
+            level: 2,
+            name: name.to_string(),
+            input: "09.in".to_string(),
+            statistics: statistics(median_micros),
+        }
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let history = BenchHistory::for_year(temp.path(), 2024);
+        history.append(&record("abc1234-dirty", "solve_with_blocks", 250)).unwrap();
+
+        assert_eq!(history.records().unwrap(), vec![record("abc1234-dirty", "solve_with_blocks", 250)]);
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("2024").join("benchmarks.tsv")).unwrap(),
+            "1733011200\tabc1234-dirty\t9\t2\tsolve_with_blocks\t09.in\t100\t125000\t250000\t250000\t1234\t500000\n"
+        );
+    }
+
+    #[test]
+    fn test_history_returns_last_record_per_solver() {
+        let temp = TempDir::new().unwrap();
+        let history = BenchHistory::for_year(temp.path(), 2024);
+        assert_eq!(history.last_for(9, 2, "solve_level2", "09.in").unwrap(), None);
+
+        history.append(&record("aaa", "solve_level2", 100)).unwrap();
+        history.append(&record("aaa", "solve_with_blocks", 200)).unwrap();
+        history.append(&record("bbb", "solve_level2", 110)).unwrap();
+
+        assert_eq!(history.last_for(9, 2, "solve_level2", "09.in").unwrap(), Some(record("bbb", "solve_level2", 110)));
+        assert_eq!(history.last_for(9, 2, "solve_level2", "09-sample.in").unwrap(), None);
+        assert_eq!(history.last_for(9, 1, "solve_level2", "09.in").unwrap(), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(&statistics(100), &statistics(105), 10.0), Comparison { delta_percent: 5.0, regressed: false });
+        assert_eq!(compare(&statistics(100), &statistics(120), 10.0), Comparison { delta_percent: 20.0, regressed: true });
+        assert_eq!(compare(&statistics(100), &statistics(50), 10.0), Comparison { delta_percent: -50.0, regressed: false });
+    }
+}
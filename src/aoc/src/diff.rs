@@ -0,0 +1,174 @@
+use std::io::IsTerminal;
+use similar::{ChangeTag, TextDiff};
+
+/// Single-line answers longer than this get a character diff instead of a one-line message
+const LONG_ANSWER: usize = 40;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether to colour output on stdout: only on a terminal, and not when `NO_COLOR` is set
+pub(crate) fn stdout_colour() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Describe why the answer doesn't match the expected answer, ending in a newline.
+///
+/// Short answers are shown on one line. Multi-line answers get a line diff and long
+/// answers point out the characters that differ.
+pub(crate) fn format_mismatch(expected: &str, actual: &str, colour: bool) -> String {
+    if expected.contains('\n') || actual.contains('\n') {
+        format_line_diff(expected, actual, colour)
+    } else if expected.chars().count() > LONG_ANSWER || actual.chars().count() > LONG_ANSWER {
+        format_character_diff(expected, actual, colour)
+    } else {
+        format!("FAILED! Expected `{}` but got `{}`\n", expected, actual)
+    }
+}
+
+fn paint(text: &str, colour_code: &str, colour: bool) -> String {
+    if colour && !text.is_empty() {
+        format!("{}{}{}", colour_code, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+// Lines
+// -----
+
+#[derive(Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Expected(&'a str),
+    Actual(&'a str),
+}
+
+/// Line diff of answers split into lines, so a missing trailing newline doesn't make a line differ
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<DiffLine<'a>> {
+    TextDiff::from_slices(expected, actual)
+        .iter_all_changes()
+        .map(|change| match change.tag() {
+            ChangeTag::Equal => DiffLine::Same(change.value()),
+            ChangeTag::Delete => DiffLine::Expected(change.value()),
+            ChangeTag::Insert => DiffLine::Actual(change.value()),
+        })
+        .collect()
+}
+
+fn format_line_diff(expected: &str, actual: &str, colour: bool) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut report = String::from("FAILED! Expected (-) and actual (+) answers differ:\n");
+    for line in diff_lines(&expected_lines, &actual_lines) {
+        let formatted = match line {
+            DiffLine::Same(text) => format!("  {}", text),
+            DiffLine::Expected(text) => paint(&format!("- {}", text), RED, colour),
+            DiffLine::Actual(text) => paint(&format!("+ {}", text), GREEN, colour),
+        };
+        report.push_str(&formatted);
+        report.push('\n');
+    }
+
+    report
+}
+
+// Characters
+// ----------
+
+/// Show both answers above each other with the characters only one of them has highlighted,
+/// and a caret below the first difference
+fn format_character_diff(expected: &str, actual: &str, colour: bool) -> String {
+    let diff = TextDiff::from_chars(expected, actual);
+    let changes: Vec<_> = diff.iter_all_changes().collect();
+
+    let first_difference = changes.iter()
+        .take_while(|change| change.tag() == ChangeTag::Equal)
+        .count();
+    let highlight = |tag: ChangeTag, colour_code: &str| {
+        let mut line = String::new();
+        for group in changes.chunk_by(|a, b| a.tag() == b.tag()) {
+            let text: String = group.iter().map(|change| change.value()).collect();
+            if group[0].tag() == ChangeTag::Equal {
+                line.push_str(&text);
+            } else if group[0].tag() == tag {
+                line.push_str(&paint(&text, colour_code, colour));
+            }
+        }
+        line
+    };
+
+    format!(
+        "FAILED! Answers differ at character {}:\n  expected: {}\n  actual:   {}\n            {}^\n",
+        first_difference + 1,
+        highlight(ChangeTag::Delete, RED),
+        highlight(ChangeTag::Insert, GREEN),
+        " ".repeat(first_difference)
+    )
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_answers_stay_on_one_line() {
+        assert_eq!(format_mismatch("31", "30", true), "FAILED! Expected `31` but got `30`\n");
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let lines = diff_lines(&["#..#", "####", "#..#"], &["#..#", "#.##", "#..#", "...."]);
+
+        assert_eq!(lines, vec![
+            DiffLine::Same("#..#"),
+            DiffLine::Expected("####"),
+            DiffLine::Actual("#.##"),
+            DiffLine::Same("#..#"),
+            DiffLine::Actual("...."),
+        ]);
+    }
+
+    #[test]
+    fn test_multi_line_answers_get_a_line_diff() {
+        assert_eq!(
+            format_mismatch("#..#\n####", "#..#\n#.##", false),
+            "FAILED! Expected (-) and actual (+) answers differ:\n  #..#\n- ####\n+ #.##\n"
+        );
+        assert_eq!(
+            format_mismatch("#..#\n####", "#..#\n#.##", true),
+            "FAILED! Expected (-) and actual (+) answers differ:\n  #..#\n\x1b[31m- ####\x1b[0m\n\x1b[32m+ #.##\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_long_answers_get_a_character_diff() {
+        let expected = "aaa,bbb,ccc,ddd,eee,fff,ggg,hhh,iii,jjj,kkk";
+        let actual = "aaa,bbb,ccc,ddd,eee,fxf,ggg,hhh,iii,jjj,kkk";
+
+        assert_eq!(
+            format_mismatch(expected, actual, false),
+            format!(
+                "FAILED! Answers differ at character 22:\n  expected: {}\n  actual:   {}\n{}^\n",
+                expected, actual, " ".repeat(12 + 21)
+            )
+        );
+        assert!(format_mismatch(expected, actual, true).contains("eee,f\x1b[32mx\x1b[0mf,ggg"));
+    }
+
+    #[test]
+    fn test_character_diff_of_different_lengths() {
+        let expected = "z00,z01,z02,z03,z04,z05,z06,z07,z08,z09,z10";
+        let actual = "z00,z01,z02,z03,z04,z05,z06,z07,z08,z09,z10,z11";
+
+        let report = format_mismatch(expected, actual, true);
+        assert!(report.starts_with("FAILED! Answers differ at character 44:\n"));
+        assert!(report.contains(&format!("  expected: {}\n", expected)));
+        assert!(report.contains(&format!("  actual:   {}\x1b[32m,z11\x1b[0m\n", expected)));
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Separator between the expected answers of level 1 and level 2 in an `.out` file
+const LEVEL_SEPARATOR: &str = "\n\n\n";
+
+/// Path of the `.out` file that belongs to the real puzzle input of a day
+pub fn expected_output_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from("input")
+        .join(year.to_string())
+        .join(format!("{:02}.out", day))
+}
+
+/// The expected answer for a level from the contents of an `.out` file
+pub fn expected_answer(contents: &str, level: u8) -> &str {
+    contents
+        .split(LEVEL_SEPARATOR)
+        .nth(level as usize - 1)
+        .map(|s| s.trim())
+        .unwrap_or("")
+}
+
+/// Replace the answer for one level in the contents of an `.out` file, keeping the other level
+pub fn with_level_answer(contents: &str, level: u8, answer: &str) -> String {
+    let mut parts: Vec<String> = [1, 2]
+        .iter()
+        .map(|&l| expected_answer(contents, l).to_string())
+        .collect();
+    parts[level as usize - 1] = answer.trim().to_string();
+
+    while parts.last().is_some_and(|p| p.is_empty()) {
+        parts.pop();
+    }
+
+    format!("{}\n", parts.join(LEVEL_SEPARATOR))
+}
+
+/// Write the answer for one level into an `.out` file, creating it if needed
+pub fn record_answer(path: &Path, level: u8, answer: &str) -> std::io::Result<()> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, with_level_answer(&contents, level, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_expected_answer() {
+        assert_eq!(expected_answer("11\n\n\n31\n", 1), "11");
+        assert_eq!(expected_answer("11\n\n\n31\n", 2), "31");
+        assert_eq!(expected_answer("11\n", 2), "");
+        assert_eq!(expected_answer("", 1), "");
+    }
+
+    #[test]
+    fn test_with_level_answer() {
+        assert_eq!(with_level_answer("", 1, "11"), "11\n");
+        assert_eq!(with_level_answer("11\n", 2, "31"), "11\n\n\n31\n");
+        assert_eq!(with_level_answer("11\n\n\n31\n", 1, "12"), "12\n\n\n31\n");
+        assert_eq!(with_level_answer("", 2, "31"), "\n\n\n31\n");
+    }
+
+    #[test]
+    fn test_record_answer() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("2024").join("01.out");
+
+        record_answer(&path, 2, "31").unwrap();
+        record_answer(&path, 1, "11").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "11\n\n\n31\n");
+    }
+}
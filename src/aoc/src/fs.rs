@@ -1,4 +1,4 @@
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 use std::collections::HashSet;
@@ -10,6 +10,8 @@ pub trait FileSystem {
     fn create_dir_all(&self, path: &Path) -> std::io::Result<()>;
     fn exists(&self, path: &Path) -> bool;
     fn write_file(&self, path: &Path, contents: &str) -> std::io::Result<()>;
+    fn read_file(&self, path: &Path) -> std::io::Result<String>;
+    fn append_file(&self, path: &Path, contents: &str) -> std::io::Result<()>;
 }
 
 pub struct RealFileSystem;
@@ -34,6 +36,17 @@ impl FileSystem for RealFileSystem {
         println!("Created file: {}", path.display());
         Ok(())
     }
+
+    fn read_file(&self, path: &Path) -> std::io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn append_file(&self, path: &Path, contents: &str) -> std::io::Result<()> {
+        let mut file = OpenOptions::new().append(true).open(path)?;
+        write!(file, "{}", contents)?;
+        println!("Appended to file: {}", path.display());
+        Ok(())
+    }
 }
 
 pub struct DryRunFileSystem {
@@ -62,11 +75,7 @@ impl FileSystem for DryRunFileSystem {
     }
 
     fn exists(&self, path: &Path) -> bool {
-        if path.exists() || self.created_paths.borrow().contains(path) {
-            true
-        } else {
-            false
-        }
+        path.exists() || self.created_paths.borrow().contains(path)
     }
 
     fn write_file(&self, path: &Path, _contents: &str) -> std::io::Result<()> {
@@ -74,4 +83,17 @@ impl FileSystem for DryRunFileSystem {
         self.created_paths.borrow_mut().insert(path.into());
         Ok(())
     }
+
+    fn read_file(&self, path: &Path) -> std::io::Result<String> {
+        if self.created_paths.borrow().contains(path) {
+            Ok(String::new())
+        } else {
+            fs::read_to_string(path)
+        }
+    }
+
+    fn append_file(&self, path: &Path, _contents: &str) -> std::io::Result<()> {
+        println!("Would append to file: {}", path.display());
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::tsv;
+
+// Submission Outcomes
+// -------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooRecent,
+    WrongLevel,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Classify the plain text response the server gives to a submission
+    pub fn from_response(response: &str) -> Verdict {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if response.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+impl Hint {
+    /// Extract the "too high" / "too low" hint from the server's response, if any
+    pub fn from_response(response: &str) -> Option<Hint> {
+        if response.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionRecord {
+    pub timestamp: i64,
+    pub day: u8,
+    pub level: u8,
+    pub verdict: Verdict,
+    #[serde(serialize_with = "serialize_hint", deserialize_with = "deserialize_hint")]
+    pub hint: Option<Hint>,
+    pub answer: String,
+}
+
+impl SubmissionRecord {
+    /// Create a record for a submission made now, based on the server's response
+    pub fn from_response(day: u8, level: u8, answer: &str, response: &str) -> SubmissionRecord {
+        SubmissionRecord {
+            timestamp: time::OffsetDateTime::now_utc().unix_timestamp(),
+            day,
+            level,
+            verdict: Verdict::from_response(response),
+            hint: Hint::from_response(response),
+            answer: answer.to_string(),
+        }
+    }
+}
+
+/// A missing hint is stored as `-`, so every line has the same number of fields
+fn serialize_hint<S: Serializer>(hint: &Option<Hint>, serializer: S) -> Result<S::Ok, S::Error> {
+    match hint {
+        Some(hint) => hint.serialize(serializer),
+        None => serializer.serialize_str("-"),
+    }
+}
+
+fn deserialize_hint<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Hint>, D::Error> {
+    match String::deserialize(deserializer)?.as_str() {
+        "too-high" => Ok(Some(Hint::TooHigh)),
+        "too-low" => Ok(Some(Hint::TooLow)),
+        _ => Ok(None),
+    }
+}
+
+// Ledger
+// ------
+
+/// The submission history of a single year, stored in `input/<year>/submissions.tsv`
+pub struct Ledger {
+    path: PathBuf,
+}
+
+impl Ledger {
+    pub fn for_year(input_dir: &Path, year: u16) -> Ledger {
+        Ledger {
+            path: input_dir.join(year.to_string()).join("submissions.tsv"),
+        }
+    }
+
+    /// All recorded submissions. A missing ledger file means nothing was submitted yet.
+    pub fn records(&self) -> std::io::Result<Vec<SubmissionRecord>> {
+        tsv::read(&self.path)
+    }
+
+    pub fn append(&self, record: &SubmissionRecord) -> std::io::Result<()> {
+        tsv::append(&self.path, record)
+    }
+}
+
+/// Check an answer against earlier submissions for the same day and level.
+///
+/// Fails if the exact answer was rejected before, or if a numeric answer is
+/// outside the bounds established by earlier "too high" / "too low" hints.
+pub fn check_answer(records: &[SubmissionRecord], day: u8, level: u8, answer: &str) -> Result<(), String> {
+    let rejected = records.iter()
+        .filter(|r| r.day == day && r.level == level && r.verdict == Verdict::Incorrect);
+
+    for record in rejected {
+        if record.answer == answer {
+            return Err(format!("Answer `{}` was already rejected{}", answer, describe_hint(record.hint)));
+        }
+
+        let bounds = (answer.parse::<i128>(), record.answer.parse::<i128>(), record.hint);
+        match bounds {
+            (Ok(candidate), Ok(bound), Some(Hint::TooHigh)) if candidate >= bound => {
+                return Err(format!("Answer `{}` is too high, `{}` was already too high", answer, record.answer));
+            }
+            (Ok(candidate), Ok(bound), Some(Hint::TooLow)) if candidate <= bound => {
+                return Err(format!("Answer `{}` is too low, `{}` was already too low", answer, record.answer));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn describe_hint(hint: Option<Hint>) -> String {
+    hint.map(|h| format!(" ({})", h)).unwrap_or_default()
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn rejected(answer: &str, hint: Option<Hint>) -> SubmissionRecord {
+        SubmissionRecord { timestamp: 0, day: 1, level: 1, verdict: Verdict::Incorrect, hint, answer: answer.to_string() }
+    }
+
+    #[test]
+    fn test_classify_response() {
+        let record = SubmissionRecord::from_response(1, 1, "42",
+            "That's not the right answer; your answer is too high. Please wait one minute before trying again.");
+        assert_eq!(record.verdict, Verdict::Incorrect);
+        assert_eq!(record.hint, Some(Hint::TooHigh));
+
+        assert_eq!(Verdict::from_response("That's the right answer! You are one gold star closer."), Verdict::Correct);
+        assert_eq!(Verdict::from_response("You gave an answer too recently; you have to wait."), Verdict::TooRecent);
+        assert_eq!(Verdict::from_response("You don't seem to be solving the right level."), Verdict::WrongLevel);
+        assert_eq!(Hint::from_response("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let ledger = Ledger::for_year(temp.path(), 2024);
+        let record = |verdict, hint, answer: &str| SubmissionRecord {
+            timestamp: 1733011200, day: 17, level: 1, verdict, hint, answer: answer.to_string(),
+        };
+        let records = vec![
+            record(Verdict::Incorrect, None, "1,2,3"),
+            record(Verdict::WrongLevel, Some(Hint::TooHigh), "#..#\n.##.\t\"x\""),
+            record(Verdict::Correct, None, "4,6,3"),
+        ];
+        for r in &records {
+            ledger.append(r).unwrap();
+        }
+
+        assert_eq!(ledger.records().unwrap(), records);
+        let contents = fs::read_to_string(temp.path().join("2024").join("submissions.tsv")).unwrap();
+        assert!(contents.starts_with("1733011200\t17\t1\tincorrect\t-\t1,2,3\n"), "{}", contents);
+    }
+
+    #[test]
+    fn test_ledger_append_and_read() {
+        let temp = TempDir::new().unwrap();
+        let ledger = Ledger::for_year(temp.path(), 2024);
+        assert!(ledger.records().unwrap().is_empty());
+
+        ledger.append(&rejected("42", Some(Hint::TooLow))).unwrap();
+        ledger.append(&rejected("50", None)).unwrap();
+
+        assert!(temp.path().join("2024").join("submissions.tsv").exists());
+        assert_eq!(ledger.records().unwrap(), vec![rejected("42", Some(Hint::TooLow)), rejected("50", None)]);
+    }
+
+    #[test]
+    fn test_check_answer() {
+        let records = vec![
+            rejected("100", Some(Hint::TooHigh)),
+            rejected("10", Some(Hint::TooLow)),
+            rejected("abc", None),
+        ];
+
+        assert!(check_answer(&records, 1, 1, "50").is_ok());
+        assert!(check_answer(&records, 1, 1, "abc").is_err());
+        assert!(check_answer(&records, 1, 1, "100").is_err());
+        assert!(check_answer(&records, 1, 1, "150").is_err());
+        assert!(check_answer(&records, 1, 1, "10").is_err());
+        assert!(check_answer(&records, 1, 1, "5").is_err());
+        assert!(check_answer(&records, 1, 2, "150").is_ok());
+        assert!(check_answer(&records, 2, 1, "abc").is_ok());
+    }
+}
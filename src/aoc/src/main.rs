@@ -3,7 +3,17 @@ use clap::{Parser, Subcommand};
 mod prep;
 mod fs;
 mod aoc_client;
+mod bench;
+mod bench_history;
+mod diff;
+mod expected_output;
+mod ledger;
+mod report;
 mod run;
+mod sample;
+mod submit;
+mod sync;
+mod tsv;
 
 extern crate solutions;
 
@@ -25,48 +35,184 @@ enum Commands {
         second: Option<String>,
         #[arg(short = 'n', long)]
         dry_run: bool,
+        /// Don't open the solution file in $VISUAL / $EDITOR
+        #[arg(long)]
+        no_edit: bool,
+        /// Also open the problem statement and sample input in the editor
+        #[arg(long, conflicts_with = "no_edit")]
+        edit_all: bool,
     },
     /// Run a solution
+    #[command(after_help = "\
+Exit codes:
+  0  every solver gave the expected answer, or there was nothing to verify against
+  1  a solver gave a wrong answer
+  2  invalid command line arguments or solver parameters
+  3  a solver panicked, timed out or returned an error
+  4  the input file or the solvers are missing
+  5  any other error")]
     Run {
-        first: Option<String>,
-        second: Option<String>,
-        third: Option<String>,
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        first: Option<YearOrDayOrInput>,
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        second: Option<YearOrDayOrInput>,
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        third: Option<YearOrDayOrInput>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        level: Option<u8>,
+        #[arg(long)]
+        solver: Option<String>,
+        /// Also run solvers that are disabled or marked as slow
         #[arg(long)]
+        all: bool,
+        /// Run every registered day of the year, or of every year when no year is given
+        #[arg(long, conflicts_with = "solver")]
+        all_days: bool,
+        /// Seconds a solver may run before it's reported as timed out. A timed out solver
+        /// can't be stopped and keeps using a CPU in the background until the run ends.
+        #[arg(long)]
+        timeout: Option<f64>,
+        /// Number of solvers to run in parallel, defaults to the number of CPUs. Solvers
+        /// that timed out still run in the background and don't count towards this limit.
+        #[arg(long, short)]
+        jobs: Option<usize>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+        /// Read the input from this file instead of one in input/<year>
+        #[arg(long, conflicts_with = "all_days")]
+        input: Option<std::path::PathBuf>,
+        /// Verify the answers against this file instead of the .out file next to the input
+        #[arg(long, conflicts_with = "all_days")]
+        expected: Option<std::path::PathBuf>,
+        /// Run every input file of the day whose suffix matches a pattern like `sample*`, or `all`
+        #[arg(long, conflicts_with_all = ["all_days", "input", "expected"], value_parser = run::parse_inputs_pattern)]
+        inputs: Option<String>,
+    },
+    /// Benchmark solutions by running them many times
+    Bench {
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        first: Option<YearOrDayOrInput>,
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        second: Option<YearOrDayOrInput>,
+        #[arg(value_parser = YearOrDayOrInput::new)]
+        third: Option<YearOrDayOrInput>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
         level: Option<u8>,
         #[arg(long)]
         solver: Option<String>,
+        /// Also benchmark solvers that are disabled or marked as slow
+        #[arg(long)]
+        all: bool,
+        /// Seconds to run each solver before measuring
+        #[arg(long, default_value_t = 1.0)]
+        warmup: f64,
+        /// Seconds to spend measuring each solver
+        #[arg(long, default_value_t = 3.0)]
+        time: f64,
+        /// Compare with the last stored results and fail if any solver regressed
+        #[arg(long)]
+        compare: bool,
+        /// Percentage the median runtime may grow before counting as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run the solver for a level and submit its answer
+    Submit {
+        /// Year and/or day, followed by the level to submit (1 or 2)
+        first: String,
+        second: Option<String>,
+        third: Option<String>,
+        #[arg(long)]
+        solver: Option<String>,
+    },
+    /// Copy already accepted answers from the website into the `.out` files
+    SyncAnswers {
+        first: Option<String>,
+        second: Option<String>,
     },
     /// List all available solvers
-    Solvers,
+    Solvers {
+        /// Check the registered solvers for duplicates and misplaced registrations instead
+        #[arg(long)]
+        check: bool,
+        /// Output format of the solver list
+        #[arg(long, value_enum, default_value_t = report::Format::Text, conflicts_with = "check")]
+        format: report::Format,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Prep { first, second, dry_run } => {
+        Commands::Prep { first, second, dry_run, no_edit, edit_all } => {
             let first = first.as_deref().map(parse_year_or_day).transpose().unwrap();
             let second = second.as_deref().map(parse_year_or_day).transpose().unwrap();
-            prep::handle(first, second, dry_run);
+            let edit_mode = match (no_edit, edit_all) {
+                (true, _) => prep::EditMode::None,
+                (false, true) => prep::EditMode::All,
+                (false, false) => prep::EditMode::Solution,
+            };
+            prep::handle(first, second, dry_run, edit_mode);
         }
-        Commands::Run { first, second, third, level, solver } => {
-            let first = first.as_deref().map(YearOrDayOrInput::new).transpose().unwrap();
-            let second = second.as_deref().map(YearOrDayOrInput::new).transpose().unwrap();
-            let third = third.as_deref().map(YearOrDayOrInput::new).transpose().unwrap();
-            let config = run::RunConfig::new(
-                first,
-                second,
-                third,
-                level,
-                solver,
-            );
+        Commands::Run { first, second, third, level, solver, all, all_days, timeout, jobs, format, input, expected, inputs } => {
+            let config = run::RunConfig {
+                timeout: timeout.map(std::time::Duration::from_secs_f64),
+                jobs,
+                format,
+                input_path: input,
+                expected_path: expected,
+                inputs,
+                ..run::RunConfig::new(first, second, third, level, solver, all, all_days)
+            };
             if let Err(e) = run::handle(config) {
+                eprintln!("Error: {}", e);
+                std::process::exit(e.exit_code());
+            }
+        }
+        Commands::Bench { first, second, third, level, solver, all, warmup, time, compare, threshold } => {
+            let config = bench::BenchConfig {
+                run: run::RunConfig::new(first, second, third, level, solver, all, false),
+                warmup_time: std::time::Duration::from_secs_f64(warmup),
+                measure_time: std::time::Duration::from_secs_f64(time),
+                compare,
+                threshold_percent: threshold,
+            };
+            if let Err(e) = bench::handle(config) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Submit { first, second, third, solver } => {
+            let result = submit::SubmitConfig::new(first, second, third, solver)
+                .map_err(|e| e.into())
+                .and_then(submit::handle);
+            if let Err(e) = result {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
         }
-        Commands::Solvers => {
-            if let Err(e) = aoc_core::list_solvers() {
+        Commands::SyncAnswers { first, second } => {
+            let result = first.as_deref().map(parse_year_or_day).transpose()
+                .and_then(|first| Ok((first, second.as_deref().map(parse_year_or_day).transpose()?)))
+                .map_err(|e| e.into())
+                .and_then(|(first, second)| {
+                    let (year, day) = prep::extract_year_and_day(first, second);
+                    sync::handle(year.unwrap_or_else(|| prep::get_current_advent().0), day)
+                });
+            if let Err(e) = result {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Solvers { check, format } => {
+            let result = if check {
+                report::write_registry_issues(&aoc_core::validate_registry(), std::io::stdout())
+            } else {
+                report::write_solvers(format, &aoc_core::registered_solvers(), std::io::stdout()).map_err(|e| e.into())
+            };
+            if let Err(e) = result {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -93,6 +239,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_submit_command_exists() {
+        let cmd = Cli::command();
+        assert!(
+            cmd.get_subcommands().any(|c| c.get_name() == "submit"),
+            "submit command should exist"
+        );
+    }
+
+    /// The exit code for arguments that are rejected before anything runs
+    fn usage_exit_code(args: &[&str]) -> Option<i32> {
+        match Cli::try_parse_from(args) {
+            Err(error) => Some(error.exit_code()),
+            Ok(Cli { command: Commands::Run { first, second, third, all_days, input, inputs, .. } }) => {
+                let config = run::RunConfig {
+                    input_path: input,
+                    inputs,
+                    ..run::RunConfig::new(first, second, third, None, None, false, all_days)
+                };
+                config.check_arguments().err().map(|error| error.exit_code())
+            }
+            Ok(_) => None,
+        }
+    }
+
+    #[test]
+    fn verify_invalid_arguments_exit_with_2() {
+        for args in [
+            &["aoc", "run", "2024", "30"][..],
+            &["aoc", "run", "2024", "1", "--level", "3"],
+            &["aoc", "run", "2024", "1", "sample", "--input", "x"],
+            &["aoc", "run", "-", "--input", "x"],
+            &["aoc", "run", "--all-days", "-"],
+            &["aoc", "run", "2024", "1", "--inputs", "sample["],
+            &["aoc", "bench", "0"],
+            &["aoc", "bench", "--level", "0"],
+        ] {
+            assert_eq!(usage_exit_code(args), Some(2), "{:?}", args);
+        }
+        assert_eq!(usage_exit_code(&["aoc", "run", "2024", "1", "sample", "--level", "2"]), None);
+    }
+
+    #[test]
+    fn verify_registered_solvers() {
+        let issues = aoc_core::validate_registry();
+        assert!(issues.is_empty(), "registered solvers have problems: {:?}", issues);
+    }
+
     #[test]
     fn verify_prep_args() {
         let cmd = Cli::command();
@@ -102,7 +296,7 @@ mod tests {
         
         let args: Vec<_> = prep_cmd.get_arguments().collect();
         
-        assert_eq!(args.len(), 3, "prep should accept two optional arguments and a dry-run flag");
+        assert_eq!(args.len(), 5, "prep should accept two optional arguments, a dry-run flag and two editor flags");
         
         // Check that dry_run flag exists with correct options
         let dry_run_arg = args.iter()
@@ -117,5 +311,10 @@ mod tests {
             dry_run_arg.get_long() == Some("dry-run"),
             "dry_run should have long flag '--dry-run'"
         );
+
+        assert!(
+            args.iter().any(|a| a.get_long() == Some("no-edit")),
+            "prep should have a '--no-edit' flag"
+        );
     }
 }
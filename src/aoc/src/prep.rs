@@ -1,9 +1,12 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use time::{OffsetDateTime, Month};
 use crate::fs::{FileSystem, RealFileSystem, DryRunFileSystem};
 use crate::aoc_client::{AdventOfCodeClient, HttpAdventOfCodeClient};
+use crate::expected_output::{expected_answer, with_level_answer};
+use crate::sample::{extract_sample, Sample};
 use std::process::Command;
 use heck::ToKebabCase;
+use scraper::{Html, Selector};
 
 // Public Interface
 // ---------------
@@ -35,8 +38,19 @@ pub fn parse_year_or_day(arg: &str) -> Result<YearOrDay, String> {
     }
 }
 
+/// Which files to open in the user's editor once preparation is done
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditMode {
+    /// Don't open an editor
+    None,
+    /// Open only the solution file
+    Solution,
+    /// Open the solution file, the problem statement and the sample input
+    All,
+}
+
 /// Handle the prep command: prepare the environment for solving a problem
-pub fn handle(first: Option<YearOrDay>, second: Option<YearOrDay>, dry_run: bool) {
+pub fn handle(first: Option<YearOrDay>, second: Option<YearOrDay>, dry_run: bool, edit_mode: EditMode) {
     let (year, day) = extract_year_and_day(first, second);
     let (current_year, current_day) = get_current_advent();
     let year = year.unwrap_or(current_year);
@@ -51,8 +65,10 @@ pub fn handle(first: Option<YearOrDay>, second: Option<YearOrDay>, dry_run: bool
     
     match HttpAdventOfCodeClient::new() {
         Ok(client) => {
-            if let Err(e) = create_files(year, day, fs, &client) {
-                eprintln!("Failed to create files: {}", e);
+            match create_files(year, day, fs, &client) {
+                Ok(Some(names)) => open_editor(&names, edit_mode, dry_run),
+                Ok(None) => {}
+                Err(e) => eprintln!("Failed to create files: {}", e),
             }
         }
         Err(e) => {
@@ -64,7 +80,7 @@ pub fn handle(first: Option<YearOrDay>, second: Option<YearOrDay>, dry_run: bool
 // Argument Handling
 // ----------------
 
-fn extract_year_and_day(first: Option<YearOrDay>, second: Option<YearOrDay>) -> (Option<u16>, Option<u8>) {
+pub(crate) fn extract_year_and_day(first: Option<YearOrDay>, second: Option<YearOrDay>) -> (Option<u16>, Option<u8>) {
     match (first, second) {
         (Some(YearOrDay::Year(y)), Some(YearOrDay::Day(d))) |
         (Some(YearOrDay::Day(d)), Some(YearOrDay::Year(y))) => (Some(y), Some(d)),
@@ -74,12 +90,12 @@ fn extract_year_and_day(first: Option<YearOrDay>, second: Option<YearOrDay>) ->
     }
 }
 
-fn get_current_advent() -> (u16, u8) {
+pub(crate) fn get_current_advent() -> (u16, u8) {
     let now = OffsetDateTime::now_utc();
     let year = now.year() as u16;
     
     let day = if now.month() == Month::December && (1..=25).contains(&now.day()) {
-        now.day() as u8
+        now.day()
     } else {
         1
     };
@@ -127,55 +143,140 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }"###;
 
-fn create_files(year: u16, day: u8, fs: &dyn FileSystem, client: &dyn AdventOfCodeClient) -> std::io::Result<()> {
+fn create_files(year: u16, day: u8, fs: &dyn FileSystem, client: &dyn AdventOfCodeClient) -> std::io::Result<Option<FileNames>> {
     let (statement, name) = match get_problem_info(year, day, client) {
         Ok((s, n)) => (s, n),
         Err(e) => {
             eprintln!("Failed to get problem info: {}", e);
-            return Ok(());
+            return Ok(None);
         }
     };
 
-    create_all_files(year, day, &name, &statement, fs, client)
+    create_all_files(year, day, &name, &statement, fs, client).map(Some)
 }
 
 fn create_all_files(year: u16, day: u8, name: &str, statement: &str, fs: &dyn FileSystem, client: &dyn AdventOfCodeClient) 
-    -> std::io::Result<()> 
+    -> std::io::Result<FileNames> 
 {
     let names = FileNames::new(year, day, name);
 
     let solution_contents = SOLUTION_TEMPLATE.replace("{year}", &year.to_string())
                                           .replace("{day}", &day.to_string())
                                           .replace("{day-long}", &format!("{:02}", day));
-    create_file(fs, names.solution, &solution_contents)?;
+    create_file(fs, &names.solution, &solution_contents)?;
 
-    if let Ok(org) = convert_html_to_org(statement) {
-        create_file(fs, names.problem, &org)?;
-    }
+    create_or_extend_problem(fs, &names.problem, statement)?;
     
-    create_file(fs, names.sample_in, "")?;
-    create_file(fs, names.sample_out, "")?;
+    create_sample_files(fs, &names, statement)?;
     
     if let Ok(input) = client.get_problem_input(year, day) {
-        create_file(fs, names.input, &input)?;
+        create_file(fs, &names.input, &input)?;
     }
     
+    Ok(names)
+}
+
+/// Create the file unless it exists. Returns whether it was created.
+fn create_file(fs: &dyn FileSystem, path: &Path, contents: &str) -> std::io::Result<bool> {
+    if fs.exists(path) {
+        return Ok(false);
+    }
+    fs.create_dir_all(path.parent().unwrap_or(path))?;
+    fs.write_file(path, contents)?;
+    Ok(true)
+}
+
+const PART_TWO_TITLE: &str = "--- Part Two ---";
+
+/// Create the problem statement, or append part two to a statement that only has part one.
+///
+/// The existing file is never overwritten so manual edits are kept.
+fn create_or_extend_problem(fs: &dyn FileSystem, path: &Path, statement: &str) -> std::io::Result<()> {
+    if !fs.exists(path) {
+        if let Ok(org) = convert_html_to_org(statement) {
+            create_file(fs, path, &org)?;
+        }
+        return Ok(());
+    }
+
+    let existing = fs.read_file(path)?;
+    if let Some(part_two) = part_two_to_append(&existing, statement) {
+        if let Ok(org) = convert_html_to_org(&part_two) {
+            fs.append_file(path, &format!("\n{}", org))?;
+        }
+    }
     Ok(())
 }
 
-fn create_file(fs: &dyn FileSystem, path: PathBuf, contents: &str) -> std::io::Result<()> {
-    if !fs.exists(&path) {
-        fs.create_dir_all(path.parent().unwrap_or(&path))?;
-        fs.write_file(&path, contents)?;
+/// Create the sample files from what can be extracted from the statement, and ask to
+/// verify them if anything was written.
+fn create_sample_files(fs: &dyn FileSystem, names: &FileNames, statement: &str) -> std::io::Result<()> {
+    let sample = extract_sample(statement);
+    if write_sample_files(fs, names, &sample)? {
+        println!(
+            "Extracted sample with {} confidence, please verify {} and {}",
+            sample.confidence,
+            names.sample_in.display(),
+            names.sample_out.display()
+        );
     }
     Ok(())
 }
 
+/// Write the sample input and output unless they exist. An existing sample output that only
+/// has level 1 gets the level 2 answer appended. Returns whether anything was written.
+fn write_sample_files(fs: &dyn FileSystem, names: &FileNames, sample: &Sample) -> std::io::Result<bool> {
+    let wrote_input = create_file(fs, &names.sample_in, sample.input.as_deref().unwrap_or(""))?;
+
+    if !fs.exists(&names.sample_out) {
+        return Ok(create_file(fs, &names.sample_out, &sample_output(sample))? || wrote_input);
+    }
+
+    let existing = fs.read_file(&names.sample_out)?;
+    match sample_output_addition(&existing, sample) {
+        Some(addition) => {
+            fs.append_file(&names.sample_out, &addition)?;
+            Ok(true)
+        }
+        None => Ok(wrote_input),
+    }
+}
+
+fn sample_output(sample: &Sample) -> String {
+    sample.answers.iter()
+        .enumerate()
+        .fold(String::new(), |contents, (index, answer)| with_level_answer(&contents, index as u8 + 1, answer))
+}
+
+/// The text to append to an existing sample output to add the level 2 answer, if it's missing
+fn sample_output_addition(existing: &str, sample: &Sample) -> Option<String> {
+    let level2 = sample.answers.get(1)?;
+    if !expected_answer(existing, 2).is_empty() {
+        return None;
+    }
+
+    let updated = with_level_answer(existing, 2, level2);
+    updated.strip_prefix(existing).map(String::from)
+}
+
+/// The HTML of part two if the statement has it but the existing org file does not
+fn part_two_to_append(existing_org: &str, statement: &str) -> Option<String> {
+    if existing_org.contains(PART_TWO_TITLE) {
+        return None;
+    }
+
+    let document = Html::parse_fragment(statement);
+    let selector = Selector::parse("article.day-desc").unwrap();
+    document.select(&selector)
+        .nth(1)
+        .map(|article| article.html())
+}
+
 fn get_problem_info(year: u16, day: u8, client: &dyn AdventOfCodeClient) 
     -> Result<(String, String), Box<dyn std::error::Error>> 
 {
@@ -214,12 +315,64 @@ impl FileNames {
     }
 }
 
+// Editor
+// ------
+
+fn open_editor(names: &FileNames, edit_mode: EditMode, dry_run: bool) {
+    let files = files_to_edit(names, edit_mode);
+    if files.is_empty() {
+        return;
+    }
+
+    let editor = std::env::var("VISUAL").ok();
+    let fallback = std::env::var("EDITOR").ok();
+    let Some(editor) = resolve_editor(editor, fallback) else {
+        eprintln!("Neither $VISUAL nor $EDITOR is set, not opening an editor");
+        return;
+    };
+
+    let (program, args) = editor_command(&editor, &files);
+    if dry_run {
+        println!("Would run: {} {}", program, args.join(" "));
+        return;
+    }
+
+    if let Err(e) = Command::new(&program).args(&args).status() {
+        eprintln!("Failed to open editor `{}`: {}", program, e);
+    }
+}
+
+fn files_to_edit(names: &FileNames, edit_mode: EditMode) -> Vec<PathBuf> {
+    match edit_mode {
+        EditMode::None => vec![],
+        EditMode::Solution => vec![names.solution.clone()],
+        EditMode::All => vec![names.solution.clone(), names.problem.clone(), names.sample_in.clone()],
+    }
+}
+
+/// Pick `$VISUAL` over `$EDITOR`, ignoring empty values
+fn resolve_editor(visual: Option<String>, editor: Option<String>) -> Option<String> {
+    visual.into_iter()
+        .chain(editor)
+        .find(|e| !e.trim().is_empty())
+}
+
+/// Split an editor setting like `code --wait` into a program and its arguments, followed by the files
+fn editor_command(editor: &str, files: &[PathBuf]) -> (String, Vec<String>) {
+    let mut parts = editor.split_whitespace().map(String::from);
+    let program = parts.next().unwrap_or_default();
+    let args = parts
+        .chain(files.iter().map(|f| f.display().to_string()))
+        .collect();
+    (program, args)
+}
+
 // Utilities
 // --------
 
 fn convert_html_to_org(html: &str) -> Result<String, Box<dyn std::error::Error>> {
     let mut child = Command::new("pandoc")
-        .args(&["-f", "html", "-t", "org"])
+        .args(["-f", "html", "-t", "org"])
         .stdin(std::process::Stdio::piped())
         .stdout(std::process::Stdio::piped())
         .spawn()?;
@@ -274,6 +427,105 @@ mod tests {
         create_files(2024, 1, &mock, &client).unwrap();
     }
 
+    #[test]
+    fn test_part_two_to_append() {
+        let part_one = r#"<article class="day-desc"><h2>--- Day 1: Test Problem ---</h2><p>One</p></article>"#;
+        let part_two = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Two</p></article>"#;
+        let both = format!("{}\n\n{}\n\n", part_one, part_two);
+
+        let appended = part_two_to_append("* --- Day 1: Test Problem ---\nOne\n", &both).unwrap();
+        assert!(appended.contains("--- Part Two ---"));
+        assert!(!appended.contains("Day 1"));
+
+        assert!(part_two_to_append("* --- Day 1: Test Problem ---\n** --- Part Two ---\n", &both).is_none());
+        assert!(part_two_to_append("* --- Day 1: Test Problem ---\nOne\n", part_one).is_none());
+    }
+
+    #[test]
+    fn test_write_sample_files_reports_whether_anything_was_written() {
+        let names = FileNames::new(2024, 1, "Test Problem");
+        let sample = Sample {
+            input: Some("3   4\n".to_string()),
+            answers: vec!["11".to_string(), "31".to_string()],
+            confidence: crate::sample::Confidence::High,
+        };
+
+        let mut complete = MockFileSystem::new();
+        complete.expect_exists().returning(|_| true);
+        complete.expect_read_file().returning(|_| Ok("11\n\n\n31\n".to_string()));
+        complete.expect_write_file().never();
+        complete.expect_append_file().never();
+        assert!(!write_sample_files(&complete, &names, &sample).unwrap());
+
+        let mut level1_only = MockFileSystem::new();
+        level1_only.expect_exists().returning(|_| true);
+        level1_only.expect_read_file().returning(|_| Ok("11\n".to_string()));
+        level1_only.expect_append_file().times(1).returning(|_, _| Ok(()));
+        assert!(write_sample_files(&level1_only, &names, &sample).unwrap());
+
+        let mut missing = MockFileSystem::new();
+        missing.expect_exists().returning(|_| false);
+        missing.expect_create_dir_all().returning(|_| Ok(()));
+        missing.expect_write_file().times(2).returning(|_, _| Ok(()));
+        assert!(write_sample_files(&missing, &names, &sample).unwrap());
+    }
+
+    #[test]
+    fn test_existing_problem_is_never_overwritten() {
+        let mut mock = MockFileSystem::new();
+        mock.expect_exists().returning(|_| true);
+        mock.expect_read_file().returning(|_| Ok("* --- Day 1: Test Problem ---\n".to_string()));
+        mock.expect_write_file().never();
+
+        let statement = r#"<article class="day-desc"><h2>--- Day 1: Test Problem ---</h2></article>"#;
+        create_or_extend_problem(&mock, Path::new("problem/2024/01-test-problem.org"), statement).unwrap();
+    }
+
+    #[test]
+    fn test_sample_output() {
+        let sample = |answers: &[&str]| Sample {
+            input: None,
+            answers: answers.iter().map(|a| a.to_string()).collect(),
+            confidence: crate::sample::Confidence::High,
+        };
+
+        assert_eq!(sample_output(&sample(&[])), "");
+        assert_eq!(sample_output(&sample(&["11"])), "11\n");
+        assert_eq!(sample_output(&sample(&["11", "31"])), "11\n\n\n31\n");
+
+        assert_eq!(sample_output_addition("11\n", &sample(&["11", "31"])), Some("\n\n31\n".to_string()));
+        assert_eq!(sample_output_addition("11", &sample(&["11", "31"])), Some("\n\n\n31\n".to_string()));
+        assert_eq!(sample_output_addition("11\n\n\n31\n", &sample(&["11", "31"])), None);
+        assert_eq!(sample_output_addition("11\n", &sample(&["11"])), None);
+    }
+
+    #[test]
+    fn test_resolve_editor() {
+        assert_eq!(resolve_editor(Some("vim".into()), Some("nano".into())), Some("vim".into()));
+        assert_eq!(resolve_editor(Some("".into()), Some("nano".into())), Some("nano".into()));
+        assert_eq!(resolve_editor(None, Some("nano".into())), Some("nano".into()));
+        assert_eq!(resolve_editor(None, None), None);
+    }
+
+    #[test]
+    fn test_editor_command() {
+        let names = FileNames::new(2024, 5, "Print Queue");
+
+        let (program, args) = editor_command("code --wait", &files_to_edit(&names, EditMode::Solution));
+        assert_eq!(program, "code");
+        assert_eq!(args, vec!["--wait", "src/solutions/src/2024/05-print-queue.rs"]);
+
+        let (program, args) = editor_command("vim", &files_to_edit(&names, EditMode::All));
+        assert_eq!(program, "vim");
+        assert_eq!(args, vec![
+            "src/solutions/src/2024/05-print-queue.rs",
+            "problem/2024/05-print-queue.org",
+            "input/2024/05-sample.in",
+        ]);
+
+        assert!(files_to_edit(&names, EditMode::None).is_empty());
+    }
+
     #[test]
     fn test_file_names_new() {
         let names = FileNames::new(2023, 5, "Some Problem Name");
@@ -0,0 +1,571 @@
+use std::io::{self, Write};
+use std::time::Duration;
+use aoc_core::{RegistryIssue, Solver, SolverInfo};
+use serde::Serialize;
+use crate::diff;
+use crate::run::{format_duration, ParseTime, SolverOutcome, Verdict};
+
+/// How the outcome of the run and solvers commands is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    Text,
+    /// A JSON array with a record per solver
+    Json,
+    /// A CSV header followed by a line per solver
+    Csv,
+}
+
+/// Receives the outcome of every solver of a run, in day and level order
+pub(crate) trait Reporter {
+    /// A day that won't be run, for example because its input is missing
+    fn skipped(&mut self, year: u16, day: u8, reason: &str) -> io::Result<()>;
+    /// A solver finished running on the input with the given file name
+    fn solver_finished(&mut self, solver: &Solver, input: &str, outcome: &SolverOutcome) -> io::Result<()>;
+    /// Called once after the last solver finished
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+/// Options that only affect the text format
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TextOptions {
+    /// End with a summary table
+    pub summary: bool,
+    /// Highlight differences between answers
+    pub colour: bool,
+    /// Print a header whenever the solvers move on to the next input file
+    pub group_by_input: bool,
+}
+
+pub(crate) fn reporter<'a>(format: Format, options: TextOptions, out: impl Write + 'a) -> Box<dyn Reporter + 'a> {
+    match format {
+        Format::Text => Box::new(TextReporter {
+            out,
+            summary: options.summary.then(Vec::new),
+            colour: options.colour,
+            group_by_input: options.group_by_input,
+            current_input: None,
+        }),
+        Format::Json => Box::new(JsonReporter { out, records: Vec::new() }),
+        Format::Csv => Box::new(CsvReporter::new(out)),
+    }
+}
+
+// Text
+// ----
+
+struct TextReporter<W> {
+    out: W,
+    summary: Option<Vec<SummaryRow>>,
+    colour: bool,
+    group_by_input: bool,
+    current_input: Option<String>,
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn skipped(&mut self, year: u16, day: u8, reason: &str) -> io::Result<()> {
+        writeln!(self.out, "Skipping {}-12-{:02}: {}", year, day, reason)?;
+        writeln!(self.out)
+    }
+
+    fn solver_finished(&mut self, solver: &Solver, input: &str, outcome: &SolverOutcome) -> io::Result<()> {
+        if self.group_by_input && self.current_input.as_deref() != Some(input) {
+            writeln!(self.out, "=== {} ===", input)?;
+            writeln!(self.out)?;
+            self.current_input = Some(input.to_string());
+        }
+        if let Some(rows) = &mut self.summary {
+            rows.push(SummaryRow {
+                year: solver.year,
+                day: solver.day,
+                level: solver.level,
+                name: solver.name.clone(),
+                verdict: outcome.verdict,
+                duration: outcome.duration,
+            });
+        }
+        write!(self.out, "{}", format_outcome(solver, outcome, self.colour))
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match &self.summary {
+            Some(rows) => write!(self.out, "{}", format_summary(rows)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The report of a single solver run, as printed by the run command
+fn format_outcome(solver: &Solver, outcome: &SolverOutcome, colour: bool) -> String {
+    let mut report = format!(
+        "Running solver for {}-12-{:02}, level {}: {}\n",
+        solver.year, solver.day, solver.level, solver.name
+    );
+
+    if outcome.verdict == Verdict::Timeout {
+        report.push_str(&format!("TIMEOUT! Solver did not finish within {}\n\n", format_duration(outcome.duration)));
+        return report;
+    }
+    if let Some(panic) = &outcome.panic {
+        report.push_str(&format!("PANICKED! {}\n", panic));
+        report.push_str(&format_durations(outcome));
+        return report;
+    }
+    if let Some(error) = &outcome.error {
+        report.push_str(&format!("ERROR! {}\n", error));
+        report.push_str(&format_durations(outcome));
+        return report;
+    }
+
+    report.push_str(&format!("{}\n", outcome.output));
+    match (outcome.verdict, &outcome.expected) {
+        (Verdict::Success, _) => report.push_str("SUCCESS!\n"),
+        (Verdict::Failed, Some(expected)) => {
+            report.push_str(&diff::format_mismatch(expected, outcome.output.trim(), colour));
+        }
+        _ => {}
+    }
+    report.push_str(&format_durations(outcome));
+
+    report
+}
+
+/// The runtime of the solver, preceded by the parse time of the input if this solver parsed it
+fn format_durations(outcome: &SolverOutcome) -> String {
+    let parsed = match outcome.parse_time {
+        Some(ParseTime::Parsed(duration)) => format!("Input parsed in {}\n", format_duration(duration)),
+        Some(ParseTime::Cached) => "Input parsed by another solver (cached)\n".to_string(),
+        None => String::new(),
+    };
+    format!("{}Solver ran in {}\n\n", parsed, format_duration(outcome.duration))
+}
+
+struct SummaryRow {
+    year: u16,
+    day: u8,
+    level: u8,
+    name: String,
+    verdict: Verdict,
+    duration: Duration,
+}
+
+/// A table with a line per solver and a total runtime per year
+fn format_summary(rows: &[SummaryRow]) -> String {
+    let name_width = rows.iter()
+        .map(|row| row.name.len())
+        .chain(std::iter::once("Solver".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:<4}  {:>3}  {:>5}  {:<name_width$}  {:<10}  {:>10}\n",
+        "Year", "Day", "Level", "Solver", "Verdict", "Runtime"
+    );
+
+    for year_rows in rows.chunk_by(|a, b| a.year == b.year) {
+        for row in year_rows {
+            table.push_str(&format!(
+                "{:<4}  {:>3}  {:>5}  {:<name_width$}  {:<10}  {:>10}\n",
+                row.year,
+                format!("{:02}", row.day),
+                row.level,
+                row.name,
+                row.verdict.to_string(),
+                format_duration(row.duration)
+            ));
+        }
+
+        let total: Duration = year_rows.iter().map(|row| row.duration).sum();
+        table.push_str(&format!(
+            "{:<4}  {:>3}  {:>5}  {:<name_width$}  {:<10}  {:>10}\n",
+            year_rows[0].year, "", "", "Total", "", format_duration(total)
+        ));
+    }
+
+    table
+}
+
+// Machine-readable
+// ----------------
+
+/// A solver run as written by the JSON and CSV reporters
+#[derive(Debug, Serialize)]
+struct SolverRecord {
+    year: u16,
+    day: u8,
+    level: u8,
+    name: String,
+    /// File name of the input
+    input: String,
+    /// Missing when the solver timed out, panicked or returned an error
+    answer: Option<String>,
+    expected: Option<String>,
+    verdict: &'static str,
+    duration_ns: u128,
+    /// Missing when the solver takes the input rather than parsed input, or when another solver parsed it
+    parse_ns: Option<u128>,
+}
+
+impl SolverRecord {
+    fn new(solver: &Solver, input: &str, outcome: &SolverOutcome) -> SolverRecord {
+        let finished = !matches!(outcome.verdict, Verdict::Timeout | Verdict::Panicked | Verdict::Error);
+        SolverRecord {
+            year: solver.year,
+            day: solver.day,
+            level: solver.level,
+            name: solver.name.clone(),
+            input: input.to_string(),
+            answer: finished.then(|| outcome.output.trim().to_string()),
+            expected: outcome.expected.clone(),
+            verdict: outcome.verdict.as_str(),
+            duration_ns: outcome.duration.as_nanos(),
+            parse_ns: match outcome.parse_time {
+                Some(ParseTime::Parsed(duration)) => Some(duration.as_nanos()),
+                Some(ParseTime::Cached) | None => None,
+            },
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 10] = [
+    "year", "day", "level", "name", "input", "answer", "expected", "verdict", "duration_ns", "parse_ns",
+];
+
+/// Writes all records as a single JSON array once the run is finished
+struct JsonReporter<W> {
+    out: W,
+    records: Vec<SolverRecord>,
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    /// Skipped days go to stderr, so the output stays valid JSON
+    fn skipped(&mut self, year: u16, day: u8, reason: &str) -> io::Result<()> {
+        eprintln!("Skipping {}-12-{:02}: {}", year, day, reason);
+        Ok(())
+    }
+
+    fn solver_finished(&mut self, solver: &Solver, input: &str, outcome: &SolverOutcome) -> io::Result<()> {
+        self.records.push(SolverRecord::new(solver, input, outcome));
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut self.out, &self.records)?;
+        writeln!(self.out)
+    }
+}
+
+/// Writes a CSV line per solver as soon as it finishes
+struct CsvReporter<W: Write> {
+    out: csv::Writer<W>,
+    wrote_header: bool,
+}
+
+impl<W: Write> CsvReporter<W> {
+    /// The header is written by hand, as the writer only adds it before the first record
+    fn new(out: W) -> CsvReporter<W> {
+        CsvReporter { out: csv::WriterBuilder::new().has_headers(false).from_writer(out), wrote_header: false }
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.wrote_header {
+            self.wrote_header = true;
+            self.out.write_record(CSV_HEADER)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for CsvReporter<W> {
+    /// Skipped days go to stderr, so the output stays valid CSV
+    fn skipped(&mut self, year: u16, day: u8, reason: &str) -> io::Result<()> {
+        eprintln!("Skipping {}-12-{:02}: {}", year, day, reason);
+        Ok(())
+    }
+
+    fn solver_finished(&mut self, solver: &Solver, input: &str, outcome: &SolverOutcome) -> io::Result<()> {
+        self.write_header()?;
+        self.out.serialize(SolverRecord::new(solver, input, outcome))?;
+        self.out.flush()
+    }
+
+    /// Without any solvers there's still a header
+    fn finish(&mut self) -> io::Result<()> {
+        self.write_header()?;
+        self.out.flush()
+    }
+}
+
+// Solver Listing
+// --------------
+
+/// A registered solver as written by `aoc solvers` in a machine-readable format
+#[derive(Debug, Serialize)]
+struct RegistrationRecord<'a> {
+    year: u16,
+    day: u8,
+    level: u8,
+    name: &'a str,
+    enabled: bool,
+    slow: bool,
+    file: &'a str,
+}
+
+impl<'a> RegistrationRecord<'a> {
+    fn new(info: &'a SolverInfo) -> RegistrationRecord<'a> {
+        RegistrationRecord {
+            year: info.year,
+            day: info.day,
+            level: info.level,
+            name: info.name,
+            enabled: info.enabled,
+            slow: info.slow,
+            file: info.file,
+        }
+    }
+}
+
+/// Write the registered solvers in the given format
+pub(crate) fn write_solvers(format: Format, solvers: &[&SolverInfo], mut out: impl Write) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for info in solvers {
+                writeln!(out, "{}", info)?;
+            }
+        }
+        Format::Json => {
+            let records: Vec<RegistrationRecord> = solvers.iter().map(|info| RegistrationRecord::new(info)).collect();
+            serde_json::to_writer_pretty(&mut out, &records)?;
+            writeln!(out)?;
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for info in solvers {
+                writer.serialize(RegistrationRecord::new(info))?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the problems found with the registered solvers, or that there are none. Fails if there are any.
+pub(crate) fn write_registry_issues(issues: &[RegistryIssue], mut out: impl Write) -> Result<(), Box<dyn std::error::Error>> {
+    for issue in issues {
+        writeln!(out, "{}", issue)?;
+    }
+
+    if issues.is_empty() {
+        writeln!(out, "No problems found")?;
+        Ok(())
+    } else {
+        Err(format!("Found {} problem(s) with registered solvers", issues.len()).into())
+    }
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run::SolverPanic;
+
+    fn solver(level: u8, name: &str) -> Solver {
+        Solver { year: 2024, day: 1, level, name: name.to_string(), func: |_, _| Ok("".into()), enabled: true, slow: false, params: &[], uses_generator: false }
+    }
+
+    fn outcome(output: &str, verdict: Verdict) -> SolverOutcome {
+        SolverOutcome {
+            output: output.to_string(),
+            expected: Some("31".to_string()),
+            verdict,
+            duration: Duration::from_millis(12),
+            parse_time: None,
+            panic: None,
+            error: None,
+        }
+    }
+
+    fn report(format: Format, options: TextOptions, outcomes: &[(Solver, SolverOutcome)]) -> String {
+        let mut out = Vec::new();
+        let mut reporter = reporter(format, options, &mut out);
+        for (solver, outcome) in outcomes {
+            let input = if solver.level == 1 { "01-sample.in" } else { "01.in" };
+            reporter.solver_finished(solver, input, outcome).unwrap();
+        }
+        reporter.finish().unwrap();
+        drop(reporter);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_format_outcome() {
+        let solver = solver(2, "solve_level2");
+
+        assert_eq!(
+            format_outcome(&solver, &outcome("30", Verdict::Failed), false),
+            "Running solver for 2024-12-01, level 2: solve_level2\n30\nFAILED! Expected `31` but got `30`\nSolver ran in 12ms\n\n"
+        );
+        assert_eq!(
+            format_outcome(&solver, &outcome("", Verdict::Timeout), false),
+            "Running solver for 2024-12-01, level 2: solve_level2\nTIMEOUT! Solver did not finish within 12ms\n\n"
+        );
+
+        let panicked = SolverOutcome {
+            panic: Some(SolverPanic { message: "not yet implemented".into(), location: Some("src/solutions/src/2024/01.rs:9:5".into()) }),
+            ..outcome("", Verdict::Panicked)
+        };
+        assert_eq!(
+            format_outcome(&solver, &panicked, false),
+            "Running solver for 2024-12-01, level 2: solve_level2\nPANICKED! at src/solutions/src/2024/01.rs:9:5: not yet implemented\nSolver ran in 12ms\n\n"
+        );
+
+        let error = SolverOutcome {
+            error: Some("invalid digit found in string".into()),
+            ..outcome("", Verdict::Error)
+        };
+        assert_eq!(
+            format_outcome(&solver, &error, false),
+            "Running solver for 2024-12-01, level 2: solve_level2\nERROR! invalid digit found in string\nSolver ran in 12ms\n\n"
+        );
+
+        let parsed = SolverOutcome { parse_time: Some(ParseTime::Parsed(Duration::from_millis(3))), ..outcome("31", Verdict::Success) };
+        assert_eq!(
+            format_outcome(&solver, &parsed, false),
+            "Running solver for 2024-12-01, level 2: solve_level2\n31\nSUCCESS!\nInput parsed in 3.000ms\nSolver ran in 12ms\n\n"
+        );
+
+        let cached = SolverOutcome { parse_time: Some(ParseTime::Cached), ..outcome("31", Verdict::Success) };
+        assert_eq!(
+            format_outcome(&solver, &cached, false),
+            "Running solver for 2024-12-01, level 2: solve_level2\n31\nSUCCESS!\nInput parsed by another solver (cached)\nSolver ran in 12ms\n\n"
+        );
+    }
+
+    #[test]
+    fn test_format_summary() {
+        let row = |year, day, level, name: &str, verdict, millis| SummaryRow {
+            year, day, level, name: name.to_string(), verdict, duration: Duration::from_millis(millis),
+        };
+        let rows = vec![
+            row(2021, 1, 1, "solve_level1", Verdict::Success, 20),
+            row(2024, 9, 1, "solve_level1", Verdict::Failed, 30),
+            row(2024, 9, 2, "solve_with_blocks", Verdict::Unverified, 40),
+        ];
+
+        let expected = "\
+Year  Day  Level  Solver             Verdict        Runtime
+2021   01      1  solve_level1       SUCCESS           20ms
+2021              Total                                20ms
+2024   09      1  solve_level1       FAILED            30ms
+2024   09      2  solve_with_blocks  unverified        40ms
+2024              Total                                70ms
+";
+        assert_eq!(format_summary(&rows), expected);
+    }
+
+    #[test]
+    fn test_text_reporter_prints_summary_when_asked() {
+        let outcomes = [(solver(2, "solve_level2"), outcome("31", Verdict::Success))];
+
+        assert!(!report(Format::Text, TextOptions::default(), &outcomes).contains("Total"));
+        let with_summary = report(Format::Text, TextOptions { summary: true, ..TextOptions::default() }, &outcomes);
+        assert!(with_summary.starts_with("Running solver for 2024-12-01, level 2: solve_level2\n31\nSUCCESS!\n"));
+        assert!(with_summary.contains("\nYear  Day  Level  Solver        Verdict        Runtime\n"));
+    }
+
+    #[test]
+    fn test_text_reporter_groups_by_input() {
+        let outcomes = [
+            (solver(1, "solve_level1"), outcome("31", Verdict::Success)),
+            (solver(1, "solve_fast"), outcome("31", Verdict::Success)),
+            (solver(2, "solve_level2"), outcome("31", Verdict::Success)),
+        ];
+
+        let grouped = report(Format::Text, TextOptions { group_by_input: true, ..TextOptions::default() }, &outcomes);
+        assert!(grouped.starts_with("=== 01-sample.in ===\n\nRunning solver for 2024-12-01, level 1: solve_level1\n"));
+        assert_eq!(grouped.matches("=== 01-sample.in ===").count(), 1);
+        assert!(grouped.contains("\n=== 01.in ===\n\nRunning solver for 2024-12-01, level 2: solve_level2\n"));
+
+        assert!(!report(Format::Text, TextOptions::default(), &outcomes).contains("==="));
+    }
+
+    #[test]
+    fn test_json_reporter() {
+        let outcomes = [
+            (solver(1, "solve_level1"), SolverOutcome { parse_time: Some(ParseTime::Parsed(Duration::from_millis(3))), ..outcome("30", Verdict::Failed) }),
+            (solver(2, "solve_level2"), outcome("", Verdict::Timeout)),
+        ];
+
+        let json: serde_json::Value = serde_json::from_str(&report(Format::Json, TextOptions::default(), &outcomes)).unwrap();
+        assert_eq!(json, serde_json::json!([
+            {
+                "year": 2024, "day": 1, "level": 1, "name": "solve_level1", "input": "01-sample.in",
+                "answer": "30", "expected": "31", "verdict": "failed", "duration_ns": 12_000_000,
+                "parse_ns": 3_000_000
+            },
+            {
+                "year": 2024, "day": 1, "level": 2, "name": "solve_level2", "input": "01.in",
+                "answer": null, "expected": "31", "verdict": "timeout", "duration_ns": 12_000_000,
+                "parse_ns": null
+            },
+        ]));
+    }
+
+    #[test]
+    fn test_csv_reporter() {
+        let outcomes = [(solver(1, "solve_level1"), outcome("4,6,3", Verdict::Failed))];
+
+        assert_eq!(
+            report(Format::Csv, TextOptions::default(), &outcomes),
+            "year,day,level,name,input,answer,expected,verdict,duration_ns,parse_ns\n2024,1,1,solve_level1,01-sample.in,\"4,6,3\",31,failed,12000000,\n"
+        );
+        assert_eq!(report(Format::Csv, TextOptions::default(), &[]), "year,day,level,name,input,answer,expected,verdict,duration_ns,parse_ns\n");
+    }
+
+    #[test]
+    fn test_csv_reporter_quotes_fields() {
+        let outcomes = [(solver(1, "solve_level1"), outcome("say \"hi\"\ntwice", Verdict::Failed))];
+
+        let csv = report(Format::Csv, TextOptions::default(), &outcomes);
+        assert_eq!(csv.lines().nth(1).unwrap(), "2024,1,1,solve_level1,01-sample.in,\"say \"\"hi\"\"");
+    }
+
+    #[test]
+    fn test_write_solvers() {
+        let info = SolverInfo {
+            year: 2024, day: 9, level: 2, name: "solve_level2", func: |_, _| Ok("".into()),
+            enabled: true, slow: false, file: "src/solutions/src/2024/09-disk-fragmenter.rs", params: &[],
+            uses_generator: false,
+        };
+        let write = |format| {
+            let mut out = Vec::new();
+            write_solvers(format, &[&info], &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        assert_eq!(write(Format::Text), "2024-09, 2, solve_level2\n");
+        assert_eq!(
+            write(Format::Csv),
+            "year,day,level,name,enabled,slow,file\n2024,9,2,solve_level2,true,false,src/solutions/src/2024/09-disk-fragmenter.rs\n"
+        );
+    }
+
+    #[test]
+    fn test_write_registry_issues() {
+        let mut out = Vec::new();
+        write_registry_issues(&[], &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "No problems found\n");
+
+        let mut out = Vec::new();
+        let issues = [RegistryIssue::MissingLevel1 { year: 2024, day: 9 }, RegistryIssue::DuplicateGenerator { year: 2024, day: 16 }];
+        let error = write_registry_issues(&issues, &mut out).unwrap_err();
+        assert_eq!(error.to_string(), "Found 2 problem(s) with registered solvers");
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2024-09: has a level 2 solver but no level 1 solver\n2024-16: has more than one generator\n"
+        );
+    }
+}
@@ -1,23 +1,42 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 use std::fs;
-use aoc_core::Solver;
+use std::io::{self, Read};
+use aoc_core::{GeneratorInfo, Params, Parsed, Solver, SolverFn, SolverInput};
+use crate::diff;
+use crate::expected_output::expected_answer;
+use crate::report::{self, Format, Reporter, TextOptions};
 
+#[derive(Debug, Clone)]
 pub enum YearOrDayOrInput {
     Year(u16),
     Day(u8),
-    Input(String)
+    Input(String),
+    /// `-`: read the input from stdin
+    Stdin,
 }
 
 impl YearOrDayOrInput {
     pub fn new(arg: &str) -> Result<Self, String> {
+        if arg == "-" {
+            return Ok(YearOrDayOrInput::Stdin);
+        }
         let num = arg.parse::<u16>();
 
         match num {
             Ok(n) => {
                 if n >= 2015 {
                     Ok(YearOrDayOrInput::Year(n))
-                } else if (1..=25).contains(&(n as u8)) {
+                } else if (1..=25).contains(&n) {
                     Ok(YearOrDayOrInput::Day(n as u8))
                 } else {
                     Err("Invalid year or day".to_string())
@@ -55,8 +74,23 @@ pub struct RunConfig {
     pub year: Option<u16>,
     pub day: Option<u8>,
     pub input_file: Option<String>,
+    /// Read the input from stdin instead of a file
+    pub stdin: bool,
+    /// Read the input from this file instead of one in `input/<year>`
+    pub input_path: Option<PathBuf>,
+    /// Verify against this file instead of the `.out` file next to the input
+    pub expected_path: Option<PathBuf>,
+    /// Run every input file of the day whose suffix matches this pattern, or all of them for `all`
+    pub inputs: Option<String>,
     pub level: Option<u8>,
     pub solver: Option<String>,
+    pub include_disabled: bool,
+    pub all_days: bool,
+    /// How long a solver may run before it's reported as timed out
+    pub timeout: Option<Duration>,
+    /// How many solvers to run at the same time, defaults to the available parallelism
+    pub jobs: Option<usize>,
+    pub format: Format,
 }
 
 impl RunConfig {
@@ -64,47 +98,275 @@ impl RunConfig {
                second: Option<YearOrDayOrInput>,
                third: Option<YearOrDayOrInput>,
                level: Option<u8>,
-               solver: Option<String>) -> RunConfig {
+               solver: Option<String>,
+               include_disabled: bool,
+               all_days: bool) -> RunConfig {
         let args = [first, second, third];
 
         let year = args.iter().filter_map(extract_year).next();
         let day = args.iter().filter_map(extract_day).next();
         let input_file = args.iter().filter_map(extract_input).next();
+        let stdin = args.iter().any(|arg| matches!(arg, Some(YearOrDayOrInput::Stdin)));
 
         RunConfig {
             year,
             day,
             input_file,
+            stdin,
+            input_path: None,
+            expected_path: None,
+            inputs: None,
             level,
-            solver
+            solver,
+            include_disabled,
+            all_days,
+            timeout: None,
+            jobs: None,
+            format: Format::Text,
         }
     }
 }
 
-/// Handle the run command: execute solution(s) for a given problem
-pub fn handle(config: RunConfig) -> Result<(), Box<dyn std::error::Error>> {
-    let (year, day) = get_year_and_day(config.year, config.day)?;
-    let input_path = resolve_input_path(year, day, config.input_file.as_deref())?;
-    let input = read_input(&input_path)?;
-    let output_path = input_path.with_extension("out");
-    let expected_output = fs::read_to_string(&output_path).ok();
+impl RunConfig {
+    /// A config for a single day with everything else left at its default
+    pub(crate) fn for_day(year: u16, day: u8) -> RunConfig {
+        RunConfig::new(Some(YearOrDayOrInput::Year(year)), Some(YearOrDayOrInput::Day(day)), None, None, None, false, false)
+    }
 
-    let solvers = discover_solvers(year, day)?;
-    let filtered_solvers = filter_solvers(solvers, config.level, config.solver.as_deref());
+    /// Fail when the input is selected in more than one way. Clap can't tell an input
+    /// suffix or `-` apart from a year or day, so these are checked after parsing.
+    pub(crate) fn check_arguments(&self) -> Result<(), RunError> {
+        let conflict = if self.all_days && self.stdin {
+            "Can't read the input of every day from stdin"
+        } else if self.inputs.is_some() && (self.stdin || self.input_file.is_some()) {
+            "Give either an input suffix, - or --inputs, not more than one"
+        } else if (self.stdin || self.input_path.is_some()) && self.input_file.is_some() {
+            "Give either an input suffix, - or --input, not more than one"
+        } else if self.stdin && self.input_path.is_some() {
+            "Give either - or --input, not both"
+        } else {
+            return Ok(());
+        };
+        Err(RunError::Usage(conflict.to_string()))
+    }
+}
 
-    for solver in filtered_solvers {
-        run_solver(&solver, &input, expected_output.as_deref())?;
+/// Why the run command failed. Each kind has its own process exit code.
+#[derive(Debug)]
+pub enum RunError {
+    /// Some solvers gave an answer that doesn't match the expected output
+    Failed(usize),
+    /// Some solvers panicked, timed out or returned an error
+    Crashed(usize),
+    /// There was no input file or no solver to run
+    Missing(String),
+    /// The arguments can't be used together
+    Usage(String),
+    Other(Box<dyn std::error::Error>),
+}
+
+impl RunError {
+    /// Exit codes, documented in the help of the run command. Clap uses 2 for invalid arguments.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RunError::Failed(_) => 1,
+            RunError::Crashed(_) => 3,
+            RunError::Missing(_) => 4,
+            RunError::Usage(_) => 2,
+            RunError::Other(_) => 5,
+        }
     }
+}
 
-    Ok(())
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Failed(count) => write!(f, "{} solver(s) gave a wrong answer", count),
+            RunError::Crashed(count) => write!(f, "{} solver(s) panicked, timed out or returned an error", count),
+            RunError::Missing(message) | RunError::Usage(message) => write!(f, "{}", message),
+            RunError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<Box<dyn std::error::Error>> for RunError {
+    fn from(e: Box<dyn std::error::Error>) -> Self {
+        RunError::Other(e)
+    }
+}
+
+impl From<std::io::Error> for RunError {
+    fn from(e: std::io::Error) -> Self {
+        RunError::Other(e.into())
+    }
 }
 
-fn get_year_and_day(year: Option<u16>, day: Option<u8>) -> Result<(u16, u8), String> {
+/// Handle the run command: execute solution(s) for a given problem.
+///
+/// Fails when any solver failed verification, panicked, timed out or returned an error, or when there
+/// was nothing to run.
+pub fn handle(config: RunConfig) -> Result<(), RunError> {
+    config.check_arguments()?;
+    let options = TextOptions {
+        summary: config.all_days,
+        colour: diff::stdout_colour(),
+        group_by_input: config.inputs.is_some(),
+    };
+    let mut reporter = report::reporter(config.format, options, io::stdout());
+    let jobs = if config.all_days {
+        all_days_jobs(&config, reporter.as_mut())?
+    } else if let Some(pattern) = &config.inputs {
+        matching_inputs_jobs(&config, pattern)?
+    } else {
+        day_jobs(&config)?
+    };
+
+    run_and_report(&jobs, &config, reporter.as_mut())
+}
+
+/// Run the jobs, report their outcomes and turn any failures into an error.
+/// Panics, timeouts and errors take precedence over wrong answers.
+fn run_and_report(jobs: &[Job], config: &RunConfig, reporter: &mut dyn Reporter) -> Result<(), RunError> {
+    if jobs.is_empty() {
+        return Err(RunError::Missing("No solvers match the selection".to_string()));
+    }
+
+    let mut failed = 0;
+    let mut crashed = 0;
+    run_jobs(jobs, worker_count(config), config.timeout, |job, outcome| {
+        match outcome.verdict {
+            Verdict::Failed => failed += 1,
+            Verdict::Panicked | Verdict::Timeout | Verdict::Error => crashed += 1,
+            Verdict::Success | Verdict::Unverified => {}
+        }
+        Ok(reporter.solver_finished(&job.solver, &job.input_name, &outcome)?)
+    })?;
+    reporter.finish()?;
+
+    if crashed > 0 {
+        Err(RunError::Crashed(crashed))
+    } else if failed > 0 {
+        Err(RunError::Failed(failed))
+    } else {
+        Ok(())
+    }
+}
+
+/// The solvers of a single day
+fn day_jobs(config: &RunConfig) -> Result<Vec<Job>, RunError> {
+    let (year, day) = get_year_and_day(config.year, config.day).map_err(|e| RunError::Other(e.into()))?;
+    let input = load_input(config, year, day)?;
+
+    let solvers = discover_solvers(year, day).map_err(RunError::Missing)?;
+    check_params(&input.params, &solvers, &input.name).map_err(RunError::Usage)?;
+    let solvers = filter_solvers(solvers, config.level, config.solver.as_deref(), config.include_disabled);
+    Ok(Job::for_input(solvers, input))
+}
+
+/// The solvers of a single day, once for every input file matching the pattern
+fn matching_inputs_jobs(config: &RunConfig, pattern: &str) -> Result<Vec<Job>, RunError> {
+    let (year, day) = get_year_and_day(config.year, config.day).map_err(|e| RunError::Other(e.into()))?;
+    let paths = matching_inputs(Path::new("input"), year, day, pattern)?;
+    if paths.is_empty() {
+        return Err(RunError::Missing(format!("No input files for {}-12-{:02} match `{}`", year, day, pattern)));
+    }
+    let solvers = discover_solvers(year, day).map_err(RunError::Missing)?;
+
+    let mut jobs = Vec::new();
+    for path in paths {
+        let input = load_input(&RunConfig { input_path: Some(path), ..RunConfig::for_day(year, day) }, year, day)?;
+        check_params(&input.params, &solvers, &input.name).map_err(RunError::Usage)?;
+        let solvers = filter_solvers(solvers.clone(), config.level, config.solver.as_deref(), config.include_disabled);
+        jobs.extend(Job::for_input(solvers, input));
+    }
+
+    Ok(jobs)
+}
+
+/// Check an `--inputs` pattern while parsing the arguments, so an invalid one is a usage error
+pub fn parse_inputs_pattern(pattern: &str) -> Result<String, String> {
+    if pattern != "all" {
+        glob::Pattern::new(pattern).map_err(|e| e.to_string())?;
+    }
+    Ok(pattern.to_string())
+}
+
+/// The input files of a day in `input_dir/<year>` whose suffix matches the pattern, sorted by name.
+/// The suffix of `16-sample2.in` is `sample2`; the real input `16.in` has an empty suffix.
+/// The pattern `all` matches every input file.
+pub(crate) fn matching_inputs(input_dir: &Path, year: u16, day: u8, pattern: &str) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let pattern = if pattern == "all" { "*" } else { pattern };
+    let pattern = glob::Pattern::new(pattern).map_err(|e| format!("Invalid input pattern `{}`: {}", pattern, e))?;
+    let day_prefix = format!("{:02}", day);
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(input_dir.join(year.to_string()))? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else { continue };
+        let Some(stem) = file_name.strip_suffix(".in") else { continue };
+        let suffix = match stem.strip_prefix(&day_prefix) {
+            Some("") => "",
+            Some(rest) => match rest.strip_prefix('-') {
+                Some(suffix) => suffix,
+                None => continue,
+            },
+            None => continue,
+        };
+        if pattern.matches(suffix) {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// The solvers of every registered day of a year, or of every year when none is given.
+/// Days without an input file are reported as skipped.
+fn all_days_jobs(config: &RunConfig, reporter: &mut dyn Reporter) -> Result<Vec<Job>, RunError> {
+    let days = aoc_core::registered_days(config.year);
+    if days.is_empty() {
+        return Err(RunError::Missing(match config.year {
+            Some(year) => format!("No solvers found for year {}", year),
+            None => "No solvers found".to_string(),
+        }));
+    }
+
+    let mut jobs = Vec::new();
+    for (year, day) in days {
+        let input_path = match resolve_input_path(year, day, config.input_file.as_deref()) {
+            Ok(path) => path,
+            Err(e) => {
+                reporter.skipped(year, day, &e)?;
+                continue;
+            }
+        };
+        let input = load_input(&RunConfig { input_path: Some(input_path), ..RunConfig::for_day(year, day) }, year, day)?;
+
+        let solvers = aoc_core::discover_solvers(year, day);
+        check_params(&input.params, &solvers, &input.name).map_err(RunError::Usage)?;
+        let solvers = filter_solvers(solvers, config.level, config.solver.as_deref(), config.include_disabled);
+        jobs.extend(Job::for_input(solvers, input));
+    }
+
+    Ok(jobs)
+}
+
+fn worker_count(config: &RunConfig) -> usize {
+    config.jobs
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+        .max(1)
+}
+
+pub(crate) fn get_year_and_day(year: Option<u16>, day: Option<u8>) -> Result<(u16, u8), String> {
     let now = time::OffsetDateTime::now_utc();
     let year = year.unwrap_or(now.year() as u16);
     let day = day.unwrap_or_else(|| {
         if now.month() == time::Month::December && (1..=25).contains(&now.day()) {
-            now.day() as u8
+            now.day()
         } else {
             1
         }
@@ -120,7 +382,7 @@ fn get_year_and_day(year: Option<u16>, day: Option<u8>) -> Result<(u16, u8), Str
     Ok((year, day))
 }
 
-fn resolve_input_path(year: u16, day: u8, input_suffix: Option<&str>) -> Result<PathBuf, String> {
+pub(crate) fn resolve_input_path(year: u16, day: u8, input_suffix: Option<&str>) -> Result<PathBuf, String> {
     let base = PathBuf::from("input").join(year.to_string());
     let filename = match input_suffix {
         Some(suffix) => format!("{:02}-{}.in", day, suffix),
@@ -135,12 +397,137 @@ fn resolve_input_path(year: u16, day: u8, input_suffix: Option<&str>) -> Result<
     Ok(path)
 }
 
-fn read_input(path: &Path) -> Result<Vec<String>, std::io::Error> {
-    let content = fs::read_to_string(path)?;
-    Ok(content.lines().map(String::from).collect())
+/// Overrides of solver parameters for an input file, read from the `.params` file next
+/// to it: one `name = value` per line, and lines starting with `#` are comments. A `#`
+/// elsewhere is part of the value, as it's a common grid character. A missing file means
+/// the solvers' defaults apply.
+pub(crate) fn read_params(input_path: &Path) -> io::Result<Vec<(String, String)>> {
+    let path = input_path.with_extension("params");
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut params = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                params.push((name.trim().to_string(), value.trim().to_string()));
+            }
+            _ => return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}: expected `name = value`, got `{}`", path.display(), index + 1, line),
+            )),
+        }
+    }
+
+    Ok(params)
+}
+
+type IsKind = fn(&str) -> bool;
+
+/// Kinds of parameter values, from the most specific. An override must be of the first kind
+/// its default is, so solvers can parse it the same way.
+const PARAM_KINDS: [(&str, IsKind); 4] = [
+    ("a non-negative integer", |value| value.parse::<u128>().is_ok()),
+    ("an integer", |value| value.parse::<i128>().is_ok()),
+    ("a number", |value| value.parse::<f64>().is_ok()),
+    ("`true` or `false`", |value| value.parse::<bool>().is_ok()),
+];
+
+/// Fail on overrides of parameters that none of the day's solvers declare, which likely are
+/// typos in the `.params` file of the input, and on values of another kind than the default
+pub(crate) fn check_params(params: &[(String, String)], solvers: &[Solver], input_name: &str) -> Result<(), String> {
+    let declared: BTreeSet<&str> = solvers.iter()
+        .flat_map(|solver| solver.params.iter().map(|(name, _)| *name))
+        .collect();
+    let Some((unknown, _)) = params.iter().find(|(name, _)| !declared.contains(name.as_str())) else {
+        return check_param_values(params, solvers, input_name);
+    };
+
+    if declared.is_empty() {
+        Err(format!("Unknown parameter `{}` for {}, its solvers take no parameters", unknown, input_name))
+    } else {
+        let names: Vec<_> = declared.iter().map(|name| format!("`{}`", name)).collect();
+        Err(format!("Unknown parameter `{}` for {}, expected one of {}", unknown, input_name, names.join(", ")))
+    }
 }
 
-fn discover_solvers(year: u16, day: u8) -> Result<Vec<Solver>, String> {
+fn check_param_values(params: &[(String, String)], solvers: &[Solver], input_name: &str) -> Result<(), String> {
+    let defaults = solvers.iter().flat_map(|solver| solver.params.iter());
+    for (name, default) in defaults {
+        let Some((_, value)) = params.iter().find(|(overridden, _)| overridden == name) else { continue };
+        let Some((kind, is_kind)) = PARAM_KINDS.iter().find(|(_, is_kind)| is_kind(default)) else { continue };
+        if !is_kind(value) {
+            return Err(format!(
+                "Parameter `{}` = `{}` for {} is invalid, expected {} like the default `{}`",
+                name, value, input_name, kind, default
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The input of a single day and the expected output to verify it against
+pub(crate) struct PuzzleInput {
+    pub text: String,
+    pub expected_output: Option<String>,
+    /// Solver parameters that differ for this input
+    pub params: Vec<(String, String)>,
+    /// File name of the input, or `-` for stdin
+    pub name: String,
+}
+
+/// Read the input selected by the config: stdin, the file given with `--input`, or
+/// a file in `input/<year>`. The expected output comes from `--expected` or else
+/// from the `.out` file next to the input file. The config must have passed `check_arguments`.
+pub(crate) fn load_input(config: &RunConfig, year: u16, day: u8) -> Result<PuzzleInput, RunError> {
+    let input_path = match (config.stdin, &config.input_path) {
+        (true, _) => None,
+        (false, Some(path)) if !path.exists() => {
+            return Err(RunError::Missing(format!("Input file not found: {}", path.display())));
+        }
+        (false, Some(path)) => Some(path.clone()),
+        (false, None) => Some(resolve_input_path(year, day, config.input_file.as_deref()).map_err(RunError::Missing)?),
+    };
+
+    let text = match &input_path {
+        Some(path) => fs::read_to_string(path)?,
+        None => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            text
+        }
+    };
+    // Solvers that take the whole text split it on "\n", so files saved with Windows line endings get the same text
+    let text = text.replace("\r\n", "\n");
+
+    let expected_output = match (&config.expected_path, &input_path) {
+        (Some(path), _) => Some(fs::read_to_string(path).map_err(|e| {
+            RunError::Missing(format!("Could not read expected output {}: {}", path.display(), e))
+        })?),
+        (None, Some(path)) => fs::read_to_string(path.with_extension("out")).ok(),
+        (None, None) => None,
+    };
+
+    let params = match &input_path {
+        Some(path) => read_params(path)?,
+        None => Vec::new(),
+    };
+
+    let name = input_path
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "-".to_string());
+
+    Ok(PuzzleInput { text, expected_output, params, name })
+}
+
+pub(crate) fn discover_solvers(year: u16, day: u8) -> Result<Vec<Solver>, String> {
     let solvers = aoc_core::discover_solvers(year, day);
     if solvers.is_empty() {
         Err(format!("No solvers found for year {} day {}", year, day))
@@ -149,52 +536,431 @@ fn discover_solvers(year: u16, day: u8) -> Result<Vec<Solver>, String> {
     }
 }
 
-fn filter_solvers(
+/// Select solvers by level and name. Solvers that don't run by default are only
+/// included when asked for, either by name or with `include_disabled`.
+pub(crate) fn filter_solvers(
     solvers: Vec<Solver>,
     level: Option<u8>,
     solver_name: Option<&str>,
+    include_disabled: bool,
 ) -> Vec<Solver> {
+    let include_disabled = include_disabled || solver_name.is_some();
     let mut filtered: Vec<Solver> = solvers
         .into_iter()
-        .filter(|s| level.map_or(true, |l| s.level == l))
-        .filter(|s| solver_name.map_or(true, |name| s.name == name))
+        .filter(|s| level.is_none_or(|l| s.level == l))
+        .filter(|s| solver_name.is_none_or(|name| s.name == name))
+        .filter(|s| include_disabled || s.runs_by_default())
         .collect();
     
     filtered.sort_by_key(|s| s.level);
     filtered
 }
 
-fn run_solver(
+/// Whether a solver's answer matched the expected output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    Success,
+    Failed,
+    /// There was no expected output to compare against
+    Unverified,
+    /// The solver didn't finish within the timeout
+    Timeout,
+    Panicked,
+    /// The solver returned an `Err` or `None`
+    Error,
+}
+
+impl Verdict {
+    /// Lower case name for machine-readable output
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Success => "success",
+            Verdict::Failed => "failed",
+            Verdict::Unverified => "unverified",
+            Verdict::Timeout => "timeout",
+            Verdict::Panicked => "panicked",
+            Verdict::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Success => write!(f, "SUCCESS"),
+            Verdict::Failed => write!(f, "FAILED"),
+            Verdict::Unverified => write!(f, "unverified"),
+            Verdict::Timeout => write!(f, "TIMEOUT"),
+            Verdict::Panicked => write!(f, "PANICKED"),
+            Verdict::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// The result of running a single solver
+#[derive(Debug)]
+pub(crate) struct SolverOutcome {
+    /// The answer of the solver, empty when it timed out, panicked or returned an error
+    pub output: String,
+    /// The expected answer for the solver's level, if known
+    pub expected: Option<String>,
+    pub verdict: Verdict,
+    /// How long the solver ran, without parsing the input
+    pub duration: Duration,
+    /// How the solver got the parsed input, for solvers that take it
+    pub parse_time: Option<ParseTime>,
+    pub panic: Option<SolverPanic>,
+    /// The error the solver returned instead of an answer
+    pub error: Option<String>,
+}
+
+impl SolverOutcome {
+    fn finished(
+        solver: &Solver,
+        result: SolverResult,
+        duration: Duration,
+        expected_output: Option<&str>,
+    ) -> SolverOutcome {
+        // An `.out` file may only hold the answer to level 1 yet, which leaves nothing to verify level 2 against
+        let expected = expected_output
+            .map(|contents| expected_answer(contents, solver.level))
+            .filter(|answer| !answer.is_empty())
+            .map(String::from);
+        let (output, panic, error) = match result {
+            SolverResult::Answer(output) => (output, None, None),
+            SolverResult::Error(error) => (String::new(), None, Some(error)),
+            SolverResult::Panic(panic) => (String::new(), Some(panic), None),
+        };
+        let verdict = match &expected {
+            _ if panic.is_some() => Verdict::Panicked,
+            _ if error.is_some() => Verdict::Error,
+            Some(expected) if output.trim() == expected => Verdict::Success,
+            Some(_) => Verdict::Failed,
+            None => Verdict::Unverified,
+        };
+
+        SolverOutcome { output, expected, verdict, duration, parse_time: None, panic, error }
+    }
+}
+
+/// Parse time of the input a solver got. Only one of the solvers sharing an input runs the generator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseTime {
+    /// The solver ran the generator, which took this long
+    Parsed(Duration),
+    /// Another solver of the same input already ran the generator
+    Cached,
+}
+
+/// Run a single solver on the current thread, report its outcome and return it
+pub(crate) fn run_solver(
     solver: &Solver,
-    input: &[String],
-    expected_output: Option<&str>,
+    puzzle: &PuzzleInput,
+    params: &Params,
+    reporter: &mut dyn Reporter,
+) -> Result<SolverOutcome, Box<dyn std::error::Error>> {
+    let text = &puzzle.text;
+    let lines: Vec<&str> = text.lines().collect();
+    let parse = solver.uses_generator.then(|| SharedParse::new(solver.year, solver.day));
+    let input = SolverInput::new(text, &lines);
+    let (result, duration, parse_time) = call_solver(solver.func, input, parse.as_ref(), params);
+
+    let expected_output = puzzle.expected_output.as_deref();
+    let outcome = SolverOutcome { parse_time, ..SolverOutcome::finished(solver, result, duration, expected_output) };
+    reporter.solver_finished(solver, &puzzle.name, &outcome)?;
+    reporter.finish()?;
+    Ok(outcome)
+}
+
+// Worker Pool
+// -----------
+
+/// A solver to run, together with the input and expected output of its day
+struct Job {
+    solver: Solver,
+    input: Arc<String>,
+    /// File name of the input, or `-` for stdin
+    input_name: String,
+    /// The solver's default parameters with the overrides for the input
+    params: Params,
+    /// The parsed input, for solvers that take it
+    parse: Option<Arc<SharedParse>>,
+    expected_output: Option<String>,
+}
+
+impl Job {
+    /// A job for each solver, all sharing the same input and the same parse of it
+    fn for_input(solvers: Vec<Solver>, input: PuzzleInput) -> Vec<Job> {
+        let text = Arc::new(input.text);
+        let parse = solvers.first().map(|solver| Arc::new(SharedParse::new(solver.year, solver.day)));
+        solvers.into_iter()
+            .map(|solver| Job {
+                params: Params::new(solver.params, &input.params),
+                parse: parse.as_ref().filter(|_| solver.uses_generator).map(Arc::clone),
+                solver,
+                input: Arc::clone(&text),
+                input_name: input.name.clone(),
+                expected_output: input.expected_output.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Solvers get a bigger stack than the default for spawned threads, as they used to run on the main thread
+const SOLVER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Run the jobs on a pool of `workers` threads. Outcomes are reported in the order of
+/// the jobs, each as soon as it and all jobs before it have finished.
+fn run_jobs(
+    jobs: &[Job],
+    workers: usize,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(&Job, SolverOutcome) -> Result<(), Box<dyn std::error::Error>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!(
-        "Running solver for {}-12-{:02}, level {}: {}",
-        solver.year, solver.day, solver.level, solver.name
-    );
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers.min(jobs.len()) {
+            let sender = sender.clone();
+            let next_job = &next_job;
+            scope.spawn(move || loop {
+                let index = next_job.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(index) else { break };
+                if sender.send((index, execute(job, timeout))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, outcome) in receiver {
+            finished.insert(index, outcome);
+            while let Some(outcome) = finished.remove(&next_to_report) {
+                report(&jobs[next_to_report], outcome?)?;
+                next_to_report += 1;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Run a job on its own thread and wait for it to finish or time out.
+///
+/// A thread can't be stopped from the outside, so a solver that timed out keeps
+/// running in the background until the process exits.
+fn execute(job: &Job, timeout: Option<Duration>) -> Result<SolverOutcome, String> {
+    let (sender, receiver) = mpsc::channel();
+    let func = job.solver.func;
+    let input = Arc::clone(&job.input);
+    let params = job.params.clone();
+    let parse = job.parse.clone();
+
+    thread::Builder::new()
+        .name(job.solver.name.clone())
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let lines: Vec<&str> = input.lines().collect();
+            let input = SolverInput::new(&input, &lines);
+            let _ = sender.send(call_solver(func, input, parse.as_deref(), &params));
+        })
+        .map_err(|e| format!("Could not start solver {}: {}", job.solver.name, e))?;
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match received {
+        Ok((result, duration, parse_time)) => Ok(SolverOutcome {
+            parse_time,
+            ..SolverOutcome::finished(&job.solver, result, duration, job.expected_output.as_deref())
+        }),
+        Err(RecvTimeoutError::Timeout) => Ok(SolverOutcome {
+            output: String::new(),
+            expected: None,
+            verdict: Verdict::Timeout,
+            duration: timeout.unwrap_or_default(),
+            parse_time: None,
+            panic: None,
+            error: None,
+        }),
+        Err(RecvTimeoutError::Disconnected) => Err(format!(
+            "Solver for {}-12-{:02}, level {} ({}) stopped without an answer",
+            job.solver.year, job.solver.day, job.solver.level, job.solver.name
+        )),
+    }
+}
+
+// Parsing
+// -------
+
+/// A day's generator and what it made of one input. Shared by the solvers of that
+/// input, so the generator runs only once, for whichever solver needs it first.
+pub(crate) struct SharedParse {
+    year: u16,
+    day: u8,
+    generator: Option<&'static GeneratorInfo>,
+    parsed: OnceLock<(Result<Parsed, SolverResult>, Duration)>,
+}
+
+impl SharedParse {
+    pub fn new(year: u16, day: u8) -> SharedParse {
+        SharedParse::with_generator(year, day, aoc_core::find_generator(year, day))
+    }
+
+    fn with_generator(year: u16, day: u8, generator: Option<&'static GeneratorInfo>) -> SharedParse {
+        SharedParse { year, day, generator, parsed: OnceLock::new() }
+    }
+
+    /// Parse the input the first time and return the result and how long parsing took.
+    /// Later calls, also from other threads, wait for and reuse that result.
+    pub fn get(&self, input: &SolverInput) -> (Result<&(dyn Any + Send + Sync), &SolverResult>, ParseTime) {
+        let mut ran_generator = false;
+        let (result, duration) = self.parsed.get_or_init(|| {
+            ran_generator = true;
+            let Some(generator) = self.generator else {
+                let error = format!("No generator registered for {}-12-{:02}", self.year, self.day);
+                return (Err(SolverResult::Error(error)), Duration::ZERO);
+            };
+
+            let (result, duration) = catch_panic(|| (generator.func)(input));
+            let result = match result {
+                Ok(Ok(parsed)) => Ok(parsed),
+                Ok(Err(error)) => Err(SolverResult::Error(format!("Generator {} failed: {}", generator.name, error))),
+                Err(panic) => Err(SolverResult::Panic(panic)),
+            };
+            (result, duration)
+        });
+
+        let time = if ran_generator { ParseTime::Parsed(*duration) } else { ParseTime::Cached };
+        (result.as_ref().map(|parsed| parsed.as_ref()), time)
+    }
+}
+
+// Panics
+// ------
+
+/// A panic caught while running a solver
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SolverPanic {
+    pub message: String,
+    /// Source location of the panic, as `file:line:column`
+    pub location: Option<String>,
+}
+
+impl fmt::Display for SolverPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "at {}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    /// Set while a solver runs on this thread, so the panic hook stays quiet
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic caught on this thread
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// Replace the panic hook with one that records the location of panics in solvers
+/// instead of printing them. Panics elsewhere are still printed as usual.
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANIC.get() {
+                let location = info.location().map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+                PANIC_LOCATION.set(location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// How a call to a solver ended
+#[derive(Debug, Clone)]
+pub(crate) enum SolverResult {
+    Answer(String),
+    /// The solver returned an `Err` or `None`
+    Error(String),
+    Panic(SolverPanic),
+}
+
+impl fmt::Display for SolverResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverResult::Answer(answer) => write!(f, "{}", answer),
+            SolverResult::Error(error) => write!(f, "{}", error),
+            SolverResult::Panic(panic) => write!(f, "panicked {}", panic),
+        }
+    }
+}
 
-    let input_refs: Vec<&str> = input.iter().map(|s| s.as_str()).collect();
+/// Call a solver and time it, parsing the input first when the solver takes parsed input.
+/// The `input` handed in is the unparsed one.
+/// Returns how the solver ended, how long it ran and how long parsing took. When parsing
+/// fails, that's how the solver ended.
+fn call_solver(
+    func: SolverFn,
+    input: SolverInput,
+    parse: Option<&SharedParse>,
+    params: &Params,
+) -> (SolverResult, Duration, Option<ParseTime>) {
+    let (parsed, parse_time) = match parse.map(|parse| parse.get(&input)) {
+        None => (None, None),
+        Some((Ok(parsed), time)) => (Some(parsed), Some(time)),
+        Some((Err(failure), time)) => return (failure.clone(), Duration::ZERO, Some(time)),
+    };
+
+    let input = SolverInput { parsed, ..input };
+    let (result, duration) = catch_panic(|| func(&input, params));
+    let result = match result {
+        Ok(Ok(answer)) => SolverResult::Answer(answer),
+        Ok(Err(error)) => SolverResult::Error(error),
+        Err(panic) => SolverResult::Panic(panic),
+    };
+    (result, duration, parse_time)
+}
+
+/// Call a function and time it. A panic is caught and returned as an error.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> (Result<T, SolverPanic>, Duration) {
+    install_panic_hook();
+
+    CATCHING_PANIC.set(true);
     let start = Instant::now();
-    let output = (solver.func)(&input_refs);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
     let duration = start.elapsed();
+    CATCHING_PANIC.set(false);
 
-    println!("{}", output);
+    let result = result.map_err(|payload| SolverPanic {
+        message: panic_message(payload.as_ref()),
+        location: PANIC_LOCATION.take(),
+    });
+    (result, duration)
+}
 
-    if let Some(expected) = expected_output {
-        let expected_parts: Vec<&str> = expected.split("\n\n\n").collect();
-        let expected = expected_parts.get(solver.level as usize - 1)
-            .map(|s| s.trim())
-            .unwrap_or("");
-        
-        if output.trim() == expected {
-            println!("SUCCESS!");
-        } else {
-            println!("FAILED! Expected `{}` but got `{}`", expected, output.trim());
-        }
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
     }
+}
 
-    let duration_str = if duration.as_secs() >= 3600 {
+/// Format a duration with a precision that fits its magnitude
+pub(crate) fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() >= 3600 {
         format!("{}:{:02}:{:02}.{:03}", 
             duration.as_secs() / 3600,
             (duration.as_secs() % 3600) / 60,
@@ -213,20 +979,15 @@ fn run_solver(
         format!("{}ms", duration.as_millis())
     } else if duration.as_millis() >= 1 {
         format!("{}.{:03}ms", duration.as_millis(),
-                duration.subsec_micros())
+                duration.subsec_micros() % 1000)
     } else if duration.as_micros() >= 10 {
         format!("{}μs", duration.as_micros())
     } else if duration.as_micros() >= 1 {
         format!("{}.{:03}μs", duration.as_micros(),
-                duration.subsec_nanos())
+                duration.subsec_nanos() % 1000)
     } else {
         format!("{}ns", duration.as_nanos())
-    };
-
-    println!("Solver ran in {}", duration_str);
-    println!();
-
-    Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -260,6 +1021,460 @@ mod tests {
         std::env::set_current_dir(original_dir).unwrap();
     }
 
+    #[test]
+    fn test_stdin_argument() {
+        assert!(matches!(YearOrDayOrInput::new("-"), Ok(YearOrDayOrInput::Stdin)));
+
+        let config = RunConfig::new(
+            Some(YearOrDayOrInput::Year(2024)), Some(YearOrDayOrInput::Day(5)), Some(YearOrDayOrInput::Stdin),
+            None, None, false, false,
+        );
+        assert!(config.stdin);
+        assert_eq!(config.input_file, None);
+    }
+
+    #[test]
+    fn test_load_input_from_path() {
+        let temp = TempDir::new().unwrap();
+        let input_path = temp.path().join("colleague.txt");
+        fs::write(&input_path, "3   4\n4   3\n").unwrap();
+        fs::write(temp.path().join("colleague.out"), "2\n").unwrap();
+        let other_expected = temp.path().join("answers.txt");
+        fs::write(&other_expected, "5\n").unwrap();
+
+        let from_path = RunConfig { input_path: Some(input_path.clone()), ..config() };
+        let input = load_input(&from_path, 2024, 1).unwrap();
+        assert_eq!(input.text, "3   4\n4   3\n");
+        assert_eq!(input.expected_output.as_deref(), Some("2\n"));
+        assert_eq!(input.name, "colleague.txt");
+
+        let with_expected = RunConfig { input_path: Some(input_path), expected_path: Some(other_expected), ..config() };
+        assert_eq!(load_input(&with_expected, 2024, 1).unwrap().expected_output.as_deref(), Some("5\n"));
+    }
+
+    #[test]
+    fn test_load_input_normalises_line_endings() {
+        let temp = TempDir::new().unwrap();
+        let input_path = temp.path().join("windows.txt");
+        fs::write(&input_path, "#####\r\n.....\r\n\r\n.....\r\n#####\r\n").unwrap();
+
+        let input = load_input(&RunConfig { input_path: Some(input_path), ..config() }, 2024, 25).unwrap();
+        assert_eq!(input.text, "#####\n.....\n\n.....\n#####\n");
+        assert_eq!(input.text.split("\n\n").count(), 2);
+    }
+
+    #[test]
+    fn test_read_params() {
+        let temp = TempDir::new().unwrap();
+        let input_path = temp.path().join("18-sample.in");
+        assert_eq!(read_params(&input_path).unwrap(), vec![]);
+
+        fs::write(temp.path().join("18-sample.params"), "# small grid\ndim = 7\n\n  # bytes\ndrop=12\nwall = #\n").unwrap();
+        assert_eq!(read_params(&input_path).unwrap(), vec![
+            ("dim".to_string(), "7".to_string()),
+            ("drop".to_string(), "12".to_string()),
+            ("wall".to_string(), "#".to_string()),
+        ]);
+
+        fs::write(temp.path().join("18-sample.params"), "dim 7\n").unwrap();
+        let error = read_params(&input_path).unwrap_err();
+        assert!(error.to_string().ends_with("18-sample.params:1: expected `name = value`, got `dim 7`"));
+    }
+
+    #[test]
+    fn test_jobs_get_params_of_their_input() {
+        let input = PuzzleInput {
+            text: String::new(),
+            expected_output: None,
+            params: vec![("dim".to_string(), "7".to_string())],
+            name: "18-sample.in".to_string(),
+        };
+        let mut solver = job(1, "solve_level1", |_, params| Ok(params.get::<usize>("dim").to_string())).solver;
+        solver.params = &[("dim", "71"), ("drop", "1024")];
+
+        let jobs = Job::for_input(vec![solver], input);
+        assert_eq!(jobs[0].params, Params::new(&[("dim", "7"), ("drop", "1024")], &[]));
+    }
+
+    #[test]
+    fn test_check_params() {
+        let mut level1 = job(1, "solve_level1", |_, _| Ok("".to_string())).solver;
+        level1.params = &[("dim", "71"), ("drop", "1024")];
+        let mut level2 = job(2, "solve_level2", |_, _| Ok("".to_string())).solver;
+        level2.params = &[("dim", "71")];
+        let overrides = |names: &[&str]| -> Vec<(String, String)> {
+            names.iter().map(|name| (name.to_string(), "7".to_string())).collect()
+        };
+
+        assert_eq!(check_params(&overrides(&["dim", "drop"]), &[level1.clone(), level2.clone()], "18-sample.in"), Ok(()));
+        assert_eq!(
+            check_params(&overrides(&["dim", "dorp"]), &[level1, level2.clone()], "18-sample.in"),
+            Err("Unknown parameter `dorp` for 18-sample.in, expected one of `dim`, `drop`".to_string()),
+        );
+        assert_eq!(
+            check_params(&[("dim".to_string(), "seven".to_string())], &[level2.clone()], "18-sample.in"),
+            Err("Parameter `dim` = `seven` for 18-sample.in is invalid, expected a non-negative integer like the default `71`".to_string()),
+        );
+        assert!(check_params(&[("dim".to_string(), "-7".to_string())], &[level2.clone()], "18-sample.in").is_err());
+        level2.params = &[("name", "abc"), ("scale", "-1.5")];
+        assert_eq!(check_params(&[("name".to_string(), "7".to_string()), ("scale".to_string(), "2".to_string())], &[level2.clone()], "18-sample.in"), Ok(()));
+        level2.params = &[];
+        assert_eq!(
+            check_params(&overrides(&["dim"]), &[level2], "01.in"),
+            Err("Unknown parameter `dim` for 01.in, its solvers take no parameters".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_load_input_errors() {
+        let missing = RunConfig { input_path: Some(PathBuf::from("does/not/exist.txt")), ..config() };
+        assert!(matches!(load_input(&missing, 2024, 1), Err(RunError::Missing(_))));
+
+    }
+
+    #[test]
+    fn test_check_arguments() {
+        assert!(config().check_arguments().is_ok());
+
+        let both = RunConfig { stdin: true, input_path: Some(PathBuf::from("input.txt")), ..config() };
+        assert!(matches!(both.check_arguments(), Err(RunError::Usage(_))));
+
+        let suffix_and_stdin = RunConfig { stdin: true, input_file: Some("sample".to_string()), ..config() };
+        assert!(matches!(suffix_and_stdin.check_arguments(), Err(RunError::Usage(_))));
+
+        let suffix_and_inputs = RunConfig { input_file: Some("sample".to_string()), inputs: Some("all".to_string()), ..config() };
+        assert!(matches!(suffix_and_inputs.check_arguments(), Err(RunError::Usage(_))));
+
+        let all_days_from_stdin = RunConfig { stdin: true, all_days: true, ..config() };
+        assert_eq!(all_days_from_stdin.check_arguments().unwrap_err().exit_code(), 2);
+    }
+
+    #[test]
+    fn test_matching_inputs() {
+        let temp = TempDir::new().unwrap();
+        let year_dir = temp.path().join("2024");
+        fs::create_dir_all(&year_dir).unwrap();
+        for name in ["16.in", "16.out", "16-sample.in", "16-sample2.in", "16-big.in", "17.in", "160.in"] {
+            fs::write(year_dir.join(name), "").unwrap();
+        }
+        let names = |pattern| matching_inputs(temp.path(), 2024, 16, pattern).unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names("all"), vec!["16-big.in", "16-sample.in", "16-sample2.in", "16.in"]);
+        assert_eq!(names("sample*"), vec!["16-sample.in", "16-sample2.in"]);
+        assert_eq!(names("sample"), vec!["16-sample.in"]);
+        assert_eq!(names("s?mple*"), vec!["16-sample.in", "16-sample2.in"]);
+        assert_eq!(names("*2"), vec!["16-sample2.in"]);
+        assert!(names("nothing").is_empty());
+
+        let error = matching_inputs(temp.path(), 2024, 16, "sample[").unwrap_err();
+        assert!(error.to_string().starts_with("Invalid input pattern `sample[`"), "{}", error);
+    }
+
+    #[test]
+    fn test_filter_solvers() {
+        let solver = |level, name: &str, enabled, slow| Solver {
+            year: 2024, day: 9, level, name: name.to_string(), func: |_, _| Ok("".into()), enabled, slow, params: &[], uses_generator: false,
+        };
+        let solvers = || vec![
+            solver(2, "solve_level2", true, false),
+            solver(2, "solve_with_blocks", false, false),
+            solver(2, "solve_brute_force", true, true),
+            solver(1, "solve_level1", true, false),
+        ];
+        let names = |solvers: Vec<Solver>| solvers.into_iter().map(|s| s.name).collect::<Vec<_>>();
+
+        assert_eq!(names(filter_solvers(solvers(), None, None, false)), vec!["solve_level1", "solve_level2"]);
+        assert_eq!(names(filter_solvers(solvers(), Some(2), None, true)), vec!["solve_level2", "solve_with_blocks", "solve_brute_force"]);
+        assert_eq!(names(filter_solvers(solvers(), None, Some("solve_with_blocks"), false)), vec!["solve_with_blocks"]);
+    }
+
+    fn job(level: u8, name: &str, func: SolverFn) -> Job {
+        Job {
+            solver: Solver { year: 2024, day: 1, level, name: name.to_string(), func, enabled: true, slow: false, params: &[], uses_generator: false },
+            input: Arc::new("3   4\n4   3\n".to_string()),
+            input_name: "01.in".to_string(),
+            params: Params::default(),
+            parse: None,
+            expected_output: Some("2\n\n\n4\n".to_string()),
+        }
+    }
+
+    fn config() -> RunConfig {
+        RunConfig::for_day(2024, 1)
+    }
+
+    fn exit_code(jobs: &[Job]) -> i32 {
+        let mut out = Vec::new();
+        let mut reporter = report::reporter(Format::Csv, TextOptions::default(), &mut out);
+        match run_and_report(jobs, &config(), reporter.as_mut()) {
+            Ok(()) => 0,
+            Err(e) => e.exit_code(),
+        }
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let correct = || job(1, "correct", |_, _| Ok("2".to_string()));
+        let wrong = || job(2, "wrong", |_, _| Ok("5".to_string()));
+        let unfinished = || job(2, "unfinished", |_, _| todo!());
+        let erroring = || job(2, "erroring", |_, _| Err("no path".to_string()));
+
+        assert_eq!(exit_code(&[correct()]), 0);
+        assert_eq!(exit_code(&[correct(), wrong()]), 1);
+        assert_eq!(exit_code(&[correct(), wrong(), unfinished()]), 3);
+        assert_eq!(exit_code(&[correct(), wrong(), erroring()]), 3);
+        assert_eq!(exit_code(&[]), 4);
+    }
+
+    #[test]
+    fn test_empty_expected_answer_is_unverified() {
+        let solver = job(2, "solve_level2", |_, _| Ok("4".to_string())).solver;
+        let answer = || SolverResult::Answer("4".to_string());
+
+        for expected in ["2\n", "2\n\n\n", "2\n\n\n  \n", ""] {
+            let outcome = SolverOutcome::finished(&solver, answer(), Duration::ZERO, Some(expected));
+            assert_eq!(outcome.verdict, Verdict::Unverified, "{:?}", expected);
+            assert_eq!(outcome.expected, None);
+        }
+
+        let mut level1_only = job(2, "solve_level2", |_, _| Ok("4".to_string()));
+        level1_only.expected_output = Some("2\n".to_string());
+        assert_eq!(exit_code(&[level1_only]), 0);
+    }
+
+    #[test]
+    fn test_handle_reports_missing_input() {
+        let config = RunConfig {
+            input_file: Some("does-not-exist".to_string()),
+            ..config()
+        };
+
+        let error = handle(config).unwrap_err();
+        assert!(matches!(error, RunError::Missing(_)));
+        assert_eq!(error.exit_code(), 4);
+    }
+
+    #[test]
+    fn test_handle_reports_year_without_solvers() {
+        let config = RunConfig {
+            year: Some(2015),
+            all_days: true,
+            ..config()
+        };
+
+        let error = handle(config).unwrap_err();
+        assert_eq!(error.to_string(), "No solvers found for year 2015");
+        assert_eq!(error.exit_code(), 4);
+    }
+
+    #[test]
+    fn test_run_jobs_reports_in_order() {
+        let jobs = vec![
+            job(1, "slow", |input, _| {
+                thread::sleep(Duration::from_millis(50));
+                Ok(input.lines.len().to_string())
+            }),
+            job(2, "fast", |input, _| Ok((input.lines.len() * 2).to_string())),
+            job(2, "wrong", |_, _| Ok("5".to_string())),
+        ];
+
+        let mut reported = Vec::new();
+        run_jobs(&jobs, 3, None, |job, outcome| {
+            reported.push((job.solver.name.clone(), outcome.verdict));
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(reported, vec![
+            ("slow".to_string(), Verdict::Success),
+            ("fast".to_string(), Verdict::Success),
+            ("wrong".to_string(), Verdict::Failed),
+        ]);
+    }
+
+    #[test]
+    fn test_run_jobs_times_out() {
+        let jobs = vec![
+            job(1, "runaway", |_, _| {
+                thread::sleep(Duration::from_secs(2));
+                Ok("2".to_string())
+            }),
+            job(2, "fast", |_, _| Ok("4".to_string())),
+        ];
+
+        let mut reported = Vec::new();
+        run_jobs(&jobs, 1, Some(Duration::from_millis(20)), |_, outcome| {
+            reported.push(outcome);
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(reported[0].verdict, Verdict::Timeout);
+        assert_eq!(reported[0].duration, Duration::from_millis(20));
+        assert_eq!(reported[1].verdict, Verdict::Success);
+    }
+
+    #[test]
+    fn test_run_jobs_catches_panics() {
+        let jobs = vec![
+            job(1, "unfinished", |_, _| todo!()),
+            job(2, "fast", |_, _| Ok("4".to_string())),
+        ];
+
+        let mut reported = Vec::new();
+        run_jobs(&jobs, 2, None, |_, outcome| {
+            reported.push(outcome);
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(reported[0].verdict, Verdict::Panicked);
+        let panic = reported[0].panic.as_ref().unwrap();
+        assert_eq!(panic.message, "not yet implemented");
+        assert!(panic.location.as_deref().unwrap().starts_with("src/aoc/src/run.rs:"));
+        assert_eq!(reported[1].verdict, Verdict::Success);
+    }
+
+    #[test]
+    fn test_run_jobs_reports_errors() {
+        let jobs = vec![
+            job(1, "erroring", |input, _| input.lines[0].parse::<u32>().map(|n| n.to_string()).map_err(|e| e.to_string())),
+            job(2, "fast", |_, _| Ok("4".to_string())),
+        ];
+
+        let mut reported = Vec::new();
+        run_jobs(&jobs, 2, None, |_, outcome| {
+            reported.push(outcome);
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(reported[0].verdict, Verdict::Error);
+        assert_eq!(reported[0].error.as_deref(), Some("invalid digit found in string"));
+        assert_eq!(reported[0].output, "");
+        assert_eq!(reported[1].verdict, Verdict::Success);
+    }
+
+    static PARSE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    static COUNTING_GENERATOR: GeneratorInfo = GeneratorInfo {
+        year: 2024, day: 1, name: "parse", file: "",
+        func: |input| {
+            PARSE_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok(Box::new(input.lines.len() as u32))
+        },
+    };
+
+    static PANICKING_GENERATOR: GeneratorInfo = GeneratorInfo {
+        year: 2024, day: 1, name: "parse", file: "", func: |_| todo!(),
+    };
+
+    /// Jobs for both levels that take the input as parsed by the generator
+    fn parsed_jobs(generator: Option<&'static GeneratorInfo>) -> Vec<Job> {
+        let parse = Arc::new(SharedParse::with_generator(2024, 1, generator));
+        [job(1, "solve_level1", |input, _| Ok(input.parsed::<u32>()?.to_string())),
+         job(2, "solve_level2", |input, _| Ok((input.parsed::<u32>()? * 2).to_string()))]
+            .into_iter()
+            .map(|mut job| {
+                job.solver.uses_generator = true;
+                job.parse = Some(Arc::clone(&parse));
+                job
+            })
+            .collect()
+    }
+
+    fn run_all(jobs: &[Job]) -> Vec<SolverOutcome> {
+        let mut reported = Vec::new();
+        run_jobs(jobs, 2, None, |_, outcome| {
+            reported.push(outcome);
+            Ok(())
+        }).unwrap();
+        reported
+    }
+
+    #[test]
+    fn test_solvers_get_the_whole_input() {
+        let mut jobs = vec![
+            job(1, "solve_level1", |input, _| Ok(input.text.split("\n\n").count().to_string())),
+            job(2, "solve_level2", |input, _| Ok(input.lines.len().to_string())),
+        ];
+        for job in &mut jobs {
+            job.input = Arc::new("1\n2\n\n3\n".to_string());
+        }
+
+        let outputs: Vec<_> = run_all(&jobs).into_iter().map(|outcome| outcome.output).collect();
+        assert_eq!(outputs, vec!["2", "4"]);
+    }
+
+    #[test]
+    fn test_jobs_share_parsed_input() {
+        let reported = run_all(&parsed_jobs(Some(&COUNTING_GENERATOR)));
+
+        assert_eq!(PARSE_COUNT.load(Ordering::SeqCst), 1);
+        assert_eq!(reported[0].verdict, Verdict::Success);
+        assert_eq!(reported[1].verdict, Verdict::Success);
+        let parse_times: Vec<_> = reported.iter().map(|outcome| outcome.parse_time).collect();
+        assert_eq!(parse_times.iter().filter(|time| matches!(time, Some(ParseTime::Parsed(_)))).count(), 1);
+        assert!(parse_times.contains(&Some(ParseTime::Cached)));
+    }
+
+    #[test]
+    fn test_second_solver_reuses_the_parse() {
+        static GENERATOR: GeneratorInfo = GeneratorInfo {
+            year: 2024, day: 1, name: "parse", file: "", func: |input| Ok(Box::new(input.lines.len() as u32)),
+        };
+
+        let mut reported = Vec::new();
+        run_jobs(&parsed_jobs(Some(&GENERATOR)), 1, None, |_, outcome| {
+            reported.push(outcome);
+            Ok(())
+        }).unwrap();
+
+        assert!(matches!(reported[0].parse_time, Some(ParseTime::Parsed(_))));
+        assert_eq!(reported[1].parse_time, Some(ParseTime::Cached));
+        assert_eq!(reported[1].verdict, Verdict::Success);
+    }
+
+    #[test]
+    fn test_generator_failures_are_reported_for_each_solver() {
+        let reported = run_all(&parsed_jobs(Some(&PANICKING_GENERATOR)));
+        assert!(reported.iter().all(|outcome| outcome.verdict == Verdict::Panicked));
+        assert_eq!(reported[1].panic.as_ref().unwrap().message, "not yet implemented");
+
+        let reported = run_all(&parsed_jobs(None));
+        assert!(reported.iter().all(|outcome| outcome.verdict == Verdict::Error));
+        assert_eq!(reported[0].error.as_deref(), Some("No generator registered for 2024-12-01"));
+    }
+
+    #[test]
+    fn test_run_solver_catches_panics() {
+        let solver = job(1, "unwraps", |input, _| Ok(input.lines[0].parse::<u32>().unwrap().to_string())).solver;
+        let input = PuzzleInput {
+            text: "3   4\n".to_string(),
+            expected_output: Some("2\n".to_string()),
+            params: vec![],
+            name: "01.in".to_string(),
+        };
+        let mut out = Vec::new();
+        let outcome = run_solver(&solver, &input, &Params::default(), report::reporter(Format::Text, TextOptions::default(), &mut out).as_mut()).unwrap();
+
+        assert_eq!(outcome.verdict, Verdict::Panicked);
+        assert_eq!(outcome.output, "");
+        assert!(outcome.panic.unwrap().message.contains("InvalidDigit"));
+        assert!(String::from_utf8(out).unwrap().starts_with("Running solver for 2024-12-01, level 1: unwraps\nPANICKED!"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_388)), "1.388μs");
+        assert_eq!(format_duration(Duration::from_nanos(27_500)), "27μs");
+        assert_eq!(format_duration(Duration::from_micros(1_050)), "1.050ms");
+        assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.500s");
+        assert_eq!(format_duration(Duration::from_secs(61)), "1:01.000");
+        assert_eq!(format_duration(Duration::from_secs(3_661)), "1:01:01.000");
+    }
+
     #[test]
     fn test_get_year_and_day() {
         assert!(matches!(get_year_and_day(Some(2015), Some(1)), Ok((2015, 1))));
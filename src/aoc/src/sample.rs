@@ -0,0 +1,147 @@
+use std::fmt;
+use scraper::{ElementRef, Html, Selector};
+
+/// How much the extracted sample can be trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confidence {
+    /// Input found right after "For example" and an answer found for every part
+    High,
+    /// Something was found, but not where it's usually found
+    Medium,
+    /// No sample input was found
+    Low,
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Confidence::High => write!(f, "high"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::Low => write!(f, "low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// The sample input, with a trailing newline
+    pub input: Option<String>,
+    /// The expected sample answer per part, in level order
+    pub answers: Vec<String>,
+    pub confidence: Confidence,
+}
+
+/// Guess the sample input and expected answers from the problem statement HTML.
+///
+/// The sample input is the largest `<pre><code>` block following "For example".
+/// The expected answer of a part is the last `<code><em>` in that part.
+pub fn extract_sample(statement: &str) -> Sample {
+    let document = Html::parse_fragment(statement);
+    let article_selector = Selector::parse("article.day-desc").unwrap();
+    let parts: Vec<ElementRef> = document.select(&article_selector).collect();
+
+    let after_example = parts.iter().find_map(|part| largest_block_after_example(*part));
+    let anywhere = || parts.iter().filter_map(|part| largest_block(*part)).max_by_key(|b| b.len());
+    let input = after_example.clone().or_else(anywhere);
+
+    let answers: Vec<Option<String>> = parts.iter().map(|part| last_emphasized_code(*part)).collect();
+    let all_answers_found = !answers.is_empty() && answers.iter().all(Option::is_some);
+
+    let confidence = match (&input, &after_example) {
+        (None, _) => Confidence::Low,
+        (Some(_), Some(_)) if all_answers_found => Confidence::High,
+        _ => Confidence::Medium,
+    };
+
+    Sample {
+        input: input.map(|i| format!("{}\n", i.trim_end_matches('\n'))),
+        answers: answers.into_iter().map_while(|a| a).collect(),
+        confidence,
+    }
+}
+
+fn largest_block_after_example(part: ElementRef) -> Option<String> {
+    let selector = Selector::parse("p, pre").unwrap();
+    let mut seen_example = false;
+    let mut largest: Option<String> = None;
+
+    for element in part.select(&selector) {
+        let text = element.text().collect::<String>();
+        match element.value().name() {
+            "p" if text.contains("For example") => seen_example = true,
+            "pre" if seen_example && largest.as_ref().is_none_or(|l| text.len() > l.len()) => {
+                largest = Some(text);
+            }
+            _ => {}
+        }
+    }
+
+    largest
+}
+
+fn largest_block(part: ElementRef) -> Option<String> {
+    let selector = Selector::parse("pre").unwrap();
+    part.select(&selector)
+        .map(|pre| pre.text().collect::<String>())
+        .max_by_key(|text| text.len())
+}
+
+fn last_emphasized_code(part: ElementRef) -> Option<String> {
+    let selector = Selector::parse("code > em, em > code").unwrap();
+    part.select(&selector)
+        .last()
+        .map(|element| element.text().collect::<String>().trim().to_string())
+        .filter(|answer| !answer.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>Maybe the lists are only off by a small amount! To find out, pair up the numbers.</p>
+<pre><code>1 2</code></pre>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+2   5
+</code></pre>
+<p>Between these pairs there is a distance of <code>2</code>.</p>
+<p>In the example above, the total distance is <code><em>11</em></code>!</p>
+</article>"#;
+
+    const PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Here are the same example lists again:</p>
+<pre><code>3   4
+</code></pre>
+<p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code>.</p>
+</article>"#;
+
+    #[test]
+    fn test_extract_sample_from_both_parts() {
+        let sample = extract_sample(&format!("{}\n\n{}\n\n", PART_ONE, PART_TWO));
+
+        assert_eq!(sample.input.as_deref(), Some("3   4\n4   3\n2   5\n"));
+        assert_eq!(sample.answers, vec!["11", "31"]);
+        assert_eq!(sample.confidence, Confidence::High);
+    }
+
+    #[test]
+    fn test_extract_sample_without_for_example() {
+        let statement = PART_ONE.replace("For example:", "Consider:");
+        let sample = extract_sample(&statement);
+
+        assert_eq!(sample.input.as_deref(), Some("3   4\n4   3\n2   5\n"));
+        assert_eq!(sample.answers, vec!["11"]);
+        assert_eq!(sample.confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_extract_sample_from_nothing() {
+        let sample = extract_sample(r#"<article class="day-desc"><p>Nothing to see here</p></article>"#);
+
+        assert_eq!(sample.input, None);
+        assert!(sample.answers.is_empty());
+        assert_eq!(sample.confidence, Confidence::Low);
+    }
+}
@@ -0,0 +1,273 @@
+use std::io;
+use std::path::Path;
+use aoc_core::{Params, Solver};
+use crate::aoc_client::{AdventOfCodeClient, HttpAdventOfCodeClient, LedgerClient};
+use crate::diff;
+use crate::expected_output;
+use crate::ledger::Verdict;
+use crate::prep::{parse_year_or_day, YearOrDay};
+use crate::report::{self, Format, Reporter, TextOptions};
+use crate::run::{self, PuzzleInput};
+use crate::sync;
+
+// Public Interface
+// ---------------
+
+#[derive(Debug)]
+pub struct SubmitConfig {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub level: u8,
+    pub solver: Option<String>,
+}
+
+impl SubmitConfig {
+    /// Build a submit configuration from the positional arguments.
+    ///
+    /// The last positional argument is always the level. Any arguments before
+    /// it are a year and/or a day, in any order.
+    pub fn new(first: String,
+               second: Option<String>,
+               third: Option<String>,
+               solver: Option<String>) -> Result<SubmitConfig, String> {
+        let mut args: Vec<String> = [Some(first), second, third].into_iter().flatten().collect();
+        let level = parse_level(&args.pop().unwrap_or_default())?;
+
+        let mut year = None;
+        let mut day = None;
+        for arg in args {
+            match parse_year_or_day(&arg)? {
+                YearOrDay::Year(y) if year.is_none() => year = Some(y),
+                YearOrDay::Day(d) if day.is_none() => day = Some(d),
+                _ => return Err(format!("Unexpected argument `{}`", arg)),
+            }
+        }
+
+        Ok(SubmitConfig {
+            year,
+            day,
+            level,
+            solver,
+        })
+    }
+}
+
+/// Handle the submit command: run the solver for a level and submit its answer
+pub fn handle(config: SubmitConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let (year, day) = run::get_year_and_day(config.year, config.day)?;
+    let input_path = run::resolve_input_path(year, day, None)?;
+    let input = PuzzleInput {
+        text: std::fs::read_to_string(&input_path)?,
+        expected_output: None,
+        params: run::read_params(&input_path)?,
+        name: input_path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+    };
+
+    let solvers = run::discover_solvers(year, day)?;
+    run::check_params(&input.params, &solvers, &input.name)?;
+    let solver = select_solver(solvers, config.level, config.solver.as_deref())?;
+
+    let client = LedgerClient::new(HttpAdventOfCodeClient::new()?, Path::new("input"));
+    let out_path = expected_output::expected_output_path(year, day);
+    let params = Params::new(solver.params, &input.params);
+    let options = TextOptions { colour: diff::stdout_colour(), ..TextOptions::default() };
+    let mut reporter = report::reporter(Format::Text, options, io::stdout());
+    let verdict = submit_solution(&solver, &input, &params, reporter.as_mut(), &client, &out_path)?;
+    println!("{}", verdict);
+
+    Ok(())
+}
+
+// Submission
+// ----------
+
+fn parse_level(arg: &str) -> Result<u8, String> {
+    match arg.parse::<u8>() {
+        Ok(level @ 1..=2) => Ok(level),
+        _ => Err(format!("Level must be 1 or 2, got `{}`", arg)),
+    }
+}
+
+fn select_solver(solvers: Vec<Solver>, level: u8, solver_name: Option<&str>) -> Result<Solver, String> {
+    let mut candidates = run::filter_solvers(solvers, Some(level), solver_name, false);
+
+    match candidates.len() {
+        0 => Err(format!("No solver found for level {}", level)),
+        1 => Ok(candidates.remove(0)),
+        _ => {
+            let names: Vec<_> = candidates.iter().map(|s| s.name.as_str()).collect();
+            Err(format!(
+                "Multiple solvers found for level {}, choose one with --solver: {}",
+                level,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+/// Run the solver, submit its answer and record it in `out_path` if the server accepts it.
+///
+/// If the level was already solved, the accepted answers are copied from the day page instead.
+fn submit_solution(
+    solver: &Solver,
+    input: &PuzzleInput,
+    params: &Params,
+    reporter: &mut dyn Reporter,
+    client: &dyn AdventOfCodeClient,
+    out_path: &Path,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let outcome = run::run_solver(solver, input, params, reporter)?;
+    if let Some(panic) = outcome.panic {
+        return Err(format!("Solver {} panicked, nothing submitted: {}", solver.name, panic).into());
+    }
+    if let Some(error) = outcome.error {
+        return Err(format!("Solver {} returned an error, nothing submitted: {}", solver.name, error).into());
+    }
+    let answer = outcome.output.trim();
+
+    println!(
+        "Submitting answer `{}` for {}-12-{:02}, level {}",
+        answer, solver.year, solver.day, solver.level
+    );
+
+    let verdict = client.submit_answer(solver.year, solver.day, solver.level, answer)?;
+
+    match Verdict::from_response(&verdict) {
+        Verdict::Correct => {
+            expected_output::record_answer(out_path, solver.level, answer)?;
+            println!("Recorded answer in {}", out_path.display());
+        }
+        Verdict::WrongLevel => {
+            sync::sync_day(solver.year, solver.day, client, out_path)?;
+        }
+        _ => {}
+    }
+
+    Ok(verdict)
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::SolverFn;
+    use crate::aoc_client::FakeClient;
+
+    /// Submit the answer of the solver on the given input, without printing its outcome
+    fn submit(solver: &Solver, text: &str, client: &FakeClient, out_path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+        let input = PuzzleInput { text: text.to_string(), expected_output: None, params: vec![], name: "01.in".to_string() };
+        let mut reporter = report::reporter(Format::Text, TextOptions::default(), io::sink());
+        submit_solution(solver, &input, &Params::default(), reporter.as_mut(), client, out_path)
+    }
+
+    fn solver(level: u8, name: &str, func: SolverFn) -> Solver {
+        Solver { year: 2024, day: 1, level, name: name.to_string(), func, enabled: true, slow: false, params: &[], uses_generator: false }
+    }
+
+    #[test]
+    fn test_submit_config_level_is_last_argument() {
+        let config = SubmitConfig::new("2024".into(), Some("5".into()), Some("2".into()), None).unwrap();
+        assert_eq!((config.year, config.day, config.level), (Some(2024), Some(5), 2));
+
+        let config = SubmitConfig::new("5".into(), Some("2024".into()), Some("1".into()), None).unwrap();
+        assert_eq!((config.year, config.day, config.level), (Some(2024), Some(5), 1));
+
+        let config = SubmitConfig::new("1".into(), None, None, None).unwrap();
+        assert_eq!((config.year, config.day, config.level), (None, None, 1));
+    }
+
+    #[test]
+    fn test_submit_config_rejects_invalid_arguments() {
+        assert!(SubmitConfig::new("3".into(), None, None, None).is_err());
+        assert!(SubmitConfig::new("2024".into(), None, None, None).is_err());
+        assert!(SubmitConfig::new("5".into(), Some("6".into()), Some("1".into()), None).is_err());
+    }
+
+    #[test]
+    fn test_select_solver() {
+        let solvers = || vec![
+            solver(1, "solve_level1", |_, _| Ok("1".into())),
+            solver(2, "solve_level2", |_, _| Ok("2".into())),
+            solver(2, "solve_fast", |_, _| Ok("2".into())),
+        ];
+
+        assert_eq!(select_solver(solvers(), 1, None).unwrap().name, "solve_level1");
+        assert_eq!(select_solver(solvers(), 2, Some("solve_fast")).unwrap().name, "solve_fast");
+        assert!(select_solver(solvers(), 2, None).is_err());
+        assert!(select_solver(solvers(), 1, Some("solve_fast")).is_err());
+    }
+
+    #[test]
+    fn test_submit_solution() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out_path = temp.path().join("01.out");
+        std::fs::write(&out_path, "11\n").unwrap();
+
+        let client = FakeClient::new("test html", "Test Problem")
+            .with_submit_response("That's the right answer!");
+        let solver = solver(2, "solve_level2", |input, _| Ok(input.lines.len().to_string()));
+        let input = "a\nb\n";
+
+        let verdict = submit(&solver, input, &client, &out_path).unwrap();
+
+        assert_eq!(verdict, "That's the right answer!");
+        assert_eq!(client.submissions(), vec![(2024, 1, 2, "2".to_string())]);
+        assert_eq!(std::fs::read_to_string(&out_path).unwrap(), "11\n\n\n2\n");
+    }
+
+    #[test]
+    fn test_submit_solution_does_not_submit_after_panic() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out_path = temp.path().join("01.out");
+
+        let client = FakeClient::new("test html", "Test Problem");
+        let solver = solver(1, "solve_level1", |_, _| todo!());
+
+        assert!(submit(&solver, "", &client, &out_path).is_err());
+        assert!(client.submissions().is_empty());
+    }
+
+    #[test]
+    fn test_submit_solution_does_not_submit_after_error() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out_path = temp.path().join("01.out");
+
+        let client = FakeClient::new("test html", "Test Problem");
+        let solver = solver(1, "solve_level1", |_, _| Err("empty input".into()));
+
+        let error = submit(&solver, "", &client, &out_path).unwrap_err();
+        assert_eq!(error.to_string(), "Solver solve_level1 returned an error, nothing submitted: empty input");
+        assert!(client.submissions().is_empty());
+    }
+
+    #[test]
+    fn test_submit_solution_syncs_already_solved_level() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out_path = temp.path().join("01.out");
+
+        let client = FakeClient::new("test html", "Test Problem")
+            .with_submit_response("You don't seem to be solving the right level. Did you already complete it?")
+            .with_accepted_answers(&["11", "31"]);
+        let solver = solver(1, "solve_level1", |_, _| Ok("11".into()));
+
+        submit(&solver, "", &client, &out_path).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&out_path).unwrap(), "11\n\n\n31\n");
+    }
+
+    #[test]
+    fn test_submit_solution_does_not_record_wrong_answer() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let out_path = temp.path().join("01.out");
+
+        let client = FakeClient::new("test html", "Test Problem")
+            .with_submit_response("That's not the right answer.");
+        let solver = solver(1, "solve_level1", |_, _| Ok("42".into()));
+
+        submit(&solver, "", &client, &out_path).unwrap();
+
+        assert!(!out_path.exists());
+    }
+}
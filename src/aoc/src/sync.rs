@@ -0,0 +1,87 @@
+use std::path::Path;
+use crate::aoc_client::{AdventOfCodeClient, HttpAdventOfCodeClient};
+use crate::expected_output;
+
+// Public Interface
+// ---------------
+
+/// Handle the sync-answers command: copy accepted answers from the website into `.out` files.
+///
+/// Without a day, every day of the year that has registered solvers is synced.
+pub fn handle(year: u16, day: Option<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => aoc_core::registered_days(Some(year)).into_iter().map(|(_, day)| day).collect(),
+    };
+
+    if days.is_empty() {
+        return Err(format!("No solvers found for year {}", year).into());
+    }
+
+    let client = HttpAdventOfCodeClient::new()?;
+    for day in days {
+        let out_path = expected_output::expected_output_path(year, day);
+        if let Err(e) = sync_day(year, day, &client, &out_path) {
+            eprintln!("Failed to sync {}-12-{:02}: {}", year, day, e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetch the accepted answers of a single day and record them in `out_path`.
+///
+/// Returns the number of levels that were recorded.
+pub fn sync_day(
+    year: u16,
+    day: u8,
+    client: &dyn AdventOfCodeClient,
+    out_path: &Path,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let answers = client.get_accepted_answers(year, day)?;
+
+    if answers.is_empty() {
+        println!("{}-12-{:02}: no accepted answers", year, day);
+        return Ok(0);
+    }
+
+    for (index, answer) in answers.iter().take(2).enumerate() {
+        let level = index as u8 + 1;
+        expected_output::record_answer(out_path, level, answer)?;
+        println!("{}-12-{:02}, level {}: `{}`", year, day, level, answer);
+    }
+    println!("Recorded answers in {}", out_path.display());
+
+    Ok(answers.len().min(2))
+}
+
+// Tests
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc_client::FakeClient;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sync_day_records_both_levels() {
+        let temp = TempDir::new().unwrap();
+        let out_path = temp.path().join("05.out");
+        let client = FakeClient::new("test html", "Test Problem")
+            .with_accepted_answers(&["143", "123"]);
+
+        assert_eq!(sync_day(2024, 5, &client, &out_path).unwrap(), 2);
+        assert_eq!(std::fs::read_to_string(&out_path).unwrap(), "143\n\n\n123\n");
+    }
+
+    #[test]
+    fn test_sync_day_without_answers_leaves_file_alone() {
+        let temp = TempDir::new().unwrap();
+        let out_path = temp.path().join("05.out");
+        let client = FakeClient::new("test html", "Test Problem");
+
+        assert_eq!(sync_day(2024, 5, &client, &out_path).unwrap(), 0);
+        assert!(!out_path.exists());
+    }
+}
@@ -0,0 +1,33 @@
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::Path;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Tab Separated Files
+// -------------------
+// A record per line without a header. Fields with a tab, quote or line break are quoted.
+
+/// All records in the file. A missing file has none, and lines that aren't a valid record are skipped.
+pub(crate) fn read<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let reader = csv::ReaderBuilder::new().delimiter(b'\t').has_headers(false).from_reader(file);
+    Ok(reader.into_deserialize().filter_map(Result::ok).collect())
+}
+
+/// Add a record to the end of the file, creating it and its folder if needed
+pub(crate) fn append<T: Serialize>(path: &Path, record: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    let mut writer = csv::WriterBuilder::new().delimiter(b'\t').has_headers(false).from_writer(file);
+    writer.serialize(record)?;
+    writer.flush()
+}
@@ -8,7 +8,7 @@ fn main() {
 
     // Collect module declarations
     let mut modules = String::new();
-    visit_dirs(&solutions_dir, &mut modules, 0).unwrap();
+    visit_dirs(solutions_dir, &mut modules, 0).unwrap();
 
     // Write the modules to all_modules.rs in src/solutions/src
     let dest_path = PathBuf::from("src").join("lib.rs");
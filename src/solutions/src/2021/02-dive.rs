@@ -51,7 +51,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(solve_level2(&input), expected);
     }
 }
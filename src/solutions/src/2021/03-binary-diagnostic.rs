@@ -32,8 +32,7 @@ pub fn solve_level2(input: &[&str]) -> u32 {
     let most_common = |zeroes, ones| if zeroes <= ones { b'1' } else { b'0' };
     let least_common = |zeroes, ones|
         if ones == 0 { b'0' }
-        else if zeroes == 0 { b'1' }
-        else if ones < zeroes { b'1' }
+        else if zeroes == 0 || ones < zeroes { b'1' }
         else { b'0' };
 
 
@@ -43,7 +42,7 @@ pub fn solve_level2(input: &[&str]) -> u32 {
     oxy * co2
 }
 
-fn find_rating<'a>(diagnostics: &[&[u8]], predicate: impl Fn(usize, usize) -> u8) -> u32 {
+fn find_rating(diagnostics: &[&[u8]], predicate: impl Fn(usize, usize) -> u8) -> u32 {
     let mut candidates = diagnostics.to_vec();
     for bit_index in 0..candidates[0].len() {
         if candidates.len() == 1 { break; }
@@ -78,7 +77,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
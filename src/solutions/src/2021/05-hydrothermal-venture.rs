@@ -33,7 +33,7 @@ impl Pipe {
         } else {
             let xs: Vec<_> = if self.x1 < self.x2 { (self.x1..=self.x2).rev().collect() } else { (self.x2..=self.x1).collect() };
             let ys: Vec<_> = if self.y1 < self.y2 { (self.y1..=self.y2).rev().collect() } else { (self.y2..=self.y1).collect() };
-            xs.into_iter().zip(ys.into_iter())
+            xs.into_iter().zip(ys)
                           .map(|(x, y)| Point { x, y })
                           .collect()
         }
@@ -44,8 +44,8 @@ impl Pipe {
 
 #[advent_of_code(2021, 5, 1)]
 pub fn solve_level1(input: &[&str]) -> usize {
-    let straight_pipes = input.into_iter()
-                              .map(|line| Pipe::new(*line))
+    let straight_pipes = input.iter()
+                              .map(|line| Pipe::new(line))
                               .filter(|pipe| pipe.x1 == pipe.x2 || pipe.y1 == pipe.y2);
 
     let counts = straight_pipes.flat_map(|pipe| pipe.pts().into_iter())
@@ -58,8 +58,8 @@ pub fn solve_level1(input: &[&str]) -> usize {
 
 #[advent_of_code(2021, 5, 2)]
 pub fn solve_level2(input: &[&str]) -> usize {
-    let pipes = input.into_iter()
-                              .map(|line| Pipe::new(*line));
+    let pipes = input.iter()
+                              .map(|line| Pipe::new(line));
 
     let counts = pipes.flat_map(|pipe| pipe.pts().into_iter())
         .fold(HashMap::new(), |counts, point| counts.update_with(point, 1, |old, new| old + new));
@@ -88,7 +88,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -6,7 +6,7 @@ use scan_fmt::scan_fmt;
 use itertools::Itertools;
 
 fn solve(initial: &str, days: i32) -> u128 {
-    let mut fish = [0 as u128; 9];
+    let mut fish = [0_u128; 9];
 
     for f in initial.split(",").map(|s| s.parse::<usize>().unwrap()) {
         fish[f] += 1;
@@ -50,7 +50,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -17,7 +17,7 @@ fn solve(positions: &Vec<i32>, cost_fn: fn(i32) -> i32) -> i32 {
     let min_pos = *positions.iter().min().unwrap();
     let max_pos = *positions.iter().max().unwrap();
 
-    let mut best = i32::max_value();
+    let mut best = i32::MAX;
     for target_pos in min_pos..=max_pos {
         let mut cost = 0;
         for source_pos in positions {
@@ -55,7 +55,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -13,7 +13,7 @@ const SEVEN: usize = 3;
 const EIGHT: usize = 7;
 
 fn count_in_line(line: &str) -> usize {
-    let output = line.split("|").skip(1).next().unwrap();
+    let output = line.split("|").nth(1).unwrap();
     let encoded_numbers: Vec<_> = output.split_whitespace().map(|s| s.len()).collect();
     encoded_numbers.iter().filter(|l| [ONE, FOUR, SEVEN, EIGHT].contains(l)).count()
 }
@@ -48,7 +48,7 @@ fn parse(line: &str) -> (Vec<HashSet<char>>, Vec<HashSet<char>>) {
     (definitions, output_numbers)
 }
 
-fn identify(definitions: &Vec<HashSet<char>>, how: impl Fn(&HashSet<char>) -> bool) -> &HashSet<char> {
+fn identify(definitions: &[HashSet<char>], how: impl Fn(&HashSet<char>) -> bool) -> &HashSet<char> {
     definitions.iter().find(|n| how(n)).expect("Expected to find exactly one match")
 }
 
@@ -80,7 +80,7 @@ pub fn solve_level2(input: &[&str]) -> usize {
 
         sum +=
             digits.iter().fold(0, |display, digit| {
-                let (next_digit, _) = mapping.iter().enumerate().find(|(_n, d)| (**d).eq(digit)).expect(&format!("Expected to find exactly one match for\n{:?}\nin\n{:?}\n", digit, mapping));
+                let (next_digit, _) = mapping.iter().enumerate().find(|(_n, d)| (**d).eq(digit)).unwrap_or_else(|| panic!("Expected to find exactly one match for\n{:?}\nin\n{:?}\n", digit, mapping));
                 display * 10 + next_digit
             });
     }
@@ -106,7 +106,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -7,7 +7,7 @@ use scan_fmt::scan_fmt;
 #[advent_of_code(2024, 1, 1)]
 pub fn solve_level1(input: &[&str]) -> i32 {
     let file: Vec<(i32, i32)> =
-        input.into_iter()
+        input.iter()
              .map(|s| scan_fmt!(s, "{} {}", i32, i32).unwrap())
              .collect();
 
@@ -53,7 +53,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
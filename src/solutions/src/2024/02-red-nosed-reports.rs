@@ -10,13 +10,13 @@ pub fn parse_report(line: &str) -> Vec<i32> {
         .collect()
 }
 
-pub fn verify_report(report: &Vec<i32>) -> bool {
+pub fn verify_report(report: &[i32]) -> bool {
     report.windows(2).all(|pair| {pair[0] - pair[1] <= 3 && pair[0] - pair[1] > 0}) ||
     report.windows(2).all(|pair| {pair[1] - pair[0] <= 3 && pair[1] - pair[0] > 0})
 }
 
-pub fn verify_report_with_safety_dampener(report: &Vec<i32>) -> bool {
-    verify_report(&report) ||
+pub fn verify_report_with_safety_dampener(report: &[i32]) -> bool {
+    verify_report(report) ||
     (0..report.len()).any(|skip| {
         let rep: Vec<_> =
             report.iter()
@@ -62,12 +62,12 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 
     #[test]
     fn test_verify() {
-        assert_eq!(verify_report(&vec![1, 3, 2, 4, 5]), false);
+        assert!(!verify_report(&[1, 3, 2, 4, 5]));
     }
 }
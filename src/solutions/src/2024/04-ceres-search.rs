@@ -6,14 +6,14 @@ use scan_fmt::scan_fmt;
 use itertools::Itertools;
 use regex::Regex;
 
-fn count_xmas(str: &String) -> usize {
+fn count_xmas(str: &str) -> usize {
     let re = Regex::new(r"(XMAS)").unwrap();
     let reversed = str.chars().rev().collect::<String>();
-    re.captures_iter(&str).count() +
+    re.captures_iter(str).count() +
         re.captures_iter(&reversed).count()
 }
 
-fn form_diagonal<I, J>(lookup: &Vec<Vec<char>>, xs: I, ys: J) -> String
+fn form_diagonal<I, J>(lookup: &[Vec<char>], xs: I, ys: J) -> String
     where I: Iterator<Item = usize>, J: Iterator<Item = usize> {
     xs.zip(ys).map(|(x, y)| lookup[y][x]).collect()
 }
@@ -30,7 +30,7 @@ pub fn solve_level1(input: &[&str]) -> usize {
 
     // horizontal
     for line in input {
-        total += count_xmas(&line.to_string());
+        total += count_xmas(line);
     }
 
     for x in 0..cols {
@@ -69,7 +69,7 @@ pub fn solve_level2(input: &[&str]) -> i32 {
                     ('S', 'M', 'M', 'S') |
                     ('M', 'S', 'S', 'M') |
                     ('S', 'M', 'S', 'M') => total += 1,
-                    _ => total = total
+                    _ => {}
                 }
             }
         }
@@ -97,7 +97,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 
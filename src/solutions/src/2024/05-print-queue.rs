@@ -46,11 +46,11 @@ pub fn solve_level2(input: &[&str]) -> u32 {
 
     updates.iter()
            .filter(|update| !update.iter().copied().is_sorted_by(|a, b| {
-               !before_map.get(a).map_or(false, |s| s.contains(b))
+               !before_map.get(a).is_some_and(|s| s.contains(b))
            }))
         .map(|update| update.iter().sorted_by(|a, b| {
             if a == b { Ordering::Equal }
-            else if before_map.get(a).map_or(false, |s|s.contains(b)) { Ordering::Greater }
+            else if before_map.get(a).is_some_and(|s|s.contains(b)) { Ordering::Greater }
             else { Ordering::Less }
         }).collect::<Vec<_>>())
         .map(|update| update[update.len() / 2])
@@ -76,7 +76,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
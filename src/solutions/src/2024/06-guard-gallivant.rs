@@ -109,7 +109,7 @@ pub fn solve_level2(input: &[&str]) -> usize {
 
         loop {
             // if we've seen the current position, we can move on
-            if vis[gy as usize][gx as usize][dir] == true { cycles += 1; break; }
+            if vis[gy as usize][gx as usize][dir] { cycles += 1; break; }
             vis[gy as usize][gx as usize][dir] = true;
 
             // step: move forward if possible
@@ -117,9 +117,9 @@ pub fn solve_level2(input: &[&str]) -> usize {
             let ny = gy + dy[dir];
 
             // We're done if we're out of bounds
-            if nx < 0 || nx >= width as i32 || ny < 0 || ny >= height as i32 { break; }
+            if nx < 0 || nx >= width || ny < 0 || ny >= height { break; }
             let mut ahead = map[ny as usize][nx as usize];
-            if ny == y as i32 && nx == x as i32 { ahead = '#' }
+            if ny == y && nx == x { ahead = '#' }
 
             // turn if blocked
             if ahead == '#' {
@@ -151,7 +151,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
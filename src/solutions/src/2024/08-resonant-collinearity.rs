@@ -14,11 +14,11 @@ pub fn solve_level1(input: &[&str]) -> usize {
 
     let mut frequency_map:HashMap<char, Vec<(i32, i32)>> = HashMap::new();
     let mut frequencies: HashSet<char> = HashSet::new();
-    for y in 0..height as usize {
-        for x in 0..width as usize {
-            if map[y][x] != '.' {
-                frequency_map.entry(map[y][x]).or_default().push((x as i32, y as i32));
-                frequencies.insert(map[y][x]);
+    for (y, row) in map.iter().enumerate() {
+        for (x, &frequency) in row.iter().enumerate() {
+            if frequency != '.' {
+                frequency_map.entry(frequency).or_default().push((x as i32, y as i32));
+                frequencies.insert(frequency);
             }
         }
     }
@@ -54,11 +54,11 @@ pub fn solve_level2(input: &[&str]) -> usize {
 
     let mut frequency_map:HashMap<char, Vec<(i32, i32)>> = HashMap::new();
     let mut frequencies: HashSet<char> = HashSet::new();
-    for y in 0..height as usize {
-        for x in 0..width as usize {
-            if map[y][x] != '.' {
-                frequency_map.entry(map[y][x]).or_default().push((x as i32, y as i32));
-                frequencies.insert(map[y][x]);
+    for (y, row) in map.iter().enumerate() {
+        for (x, &frequency) in row.iter().enumerate() {
+            if frequency != '.' {
+                frequency_map.entry(frequency).or_default().push((x as i32, y as i32));
+                frequencies.insert(frequency);
             }
         }
     }
@@ -119,7 +119,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
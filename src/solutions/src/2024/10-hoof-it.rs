@@ -18,8 +18,8 @@ pub fn solve_level1(input: &[&str]) -> u32 {
     // find trailheads
     let mut trailheads: Vec<Point> = vec![];
     for x in 0..width {
-        for y in 0..height {
-            if map[y][x] == 0 { trailheads.push((x, y)); };
+        for (y, row) in map.iter().enumerate() {
+            if row[x] == 0 { trailheads.push((x, y)); };
         }
     }
 
@@ -42,14 +42,12 @@ pub fn solve_level1(input: &[&str]) -> u32 {
             for d in 0..4 {
                 let nx = (cx as i32 + dx[d]) as usize;
                 let ny = (cy as i32 + dy[d]) as usize;
-                if nx < width && ny < height {
-                    if map[ny][nx] - map[cy][cx] == 1 {
-                        if !seen[ny][nx] {
+                if nx < width && ny < height
+                    && map[ny][nx] - map[cy][cx] == 1
+                        && !seen[ny][nx] {
                             q.push_back((nx , ny ));
                             seen[ny][nx] = true;
                         }
-                    }
-                }
             }
 
         }
@@ -67,8 +65,8 @@ pub fn solve_level2(input: &[&str]) -> u32 {
     // find trailheads
     let mut trailheads: Vec<Point> = vec![];
     for x in 0..width {
-        for y in 0..height {
-            if map[y][x] == 0 { trailheads.push((x, y)); };
+        for (y, row) in map.iter().enumerate() {
+            if row[x] == 0 { trailheads.push((x, y)); };
         }
     }
 
@@ -90,11 +88,10 @@ pub fn solve_level2(input: &[&str]) -> u32 {
             for d in 0..4 {
                 let nx = (cx as i32 + dx[d]) as usize;
                 let ny = (cy as i32 + dy[d]) as usize;
-                if nx < width && ny < height {
-                    if map[ny][nx] - map[cy][cx] == 1 {
+                if nx < width && ny < height
+                    && map[ny][nx] - map[cy][cx] == 1 {
                         q.push_back((nx , ny ));
                     }
-                }
             }
 
         }
@@ -121,7 +118,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
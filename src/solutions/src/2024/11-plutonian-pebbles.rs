@@ -46,7 +46,7 @@ fn solve_single_stone(stone: u128, step: u8, max_steps: u8, cache: &mut FxHashMa
             solve_single_stone(1, step + 1, max_steps, cache)
         } else {
             let len = stone.ilog10() + 1;
-            if len % 2 == 0 {
+            if len.is_multiple_of(2) {
                 let div = 10u128.pow(len / 2);
                 solve_single_stone(stone / div, step + 1, max_steps, cache) +
                     solve_single_stone(stone % div, step + 1, max_steps, cache)
@@ -124,14 +124,14 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 
     #[test]
     fn test_solve_with_counts() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_with_counts(&input)), expected);
     }
 }
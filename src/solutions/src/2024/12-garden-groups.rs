@@ -125,11 +125,10 @@ pub fn solve_level2(input: &[&str]) -> u64 {
 
             // collapse perimiter into sides
             let mut sides = 0;
-            for d in 0..4 {
-                let mut lookup: FxHashSet<Point> = perimiter[d].iter().copied().collect();
+            for (d, side) in perimiter.iter().enumerate() {
+                let mut lookup: FxHashSet<Point> = side.iter().copied().collect();
 
-                for i in 0..perimiter[d].len() {
-                    let c = perimiter[d][i];
+                for &c in side {
                     if !lookup.contains(&c) { continue; } // we already collapsed this point
                     lookup.remove(&c);
 
@@ -176,7 +175,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -19,10 +19,11 @@ struct Machine {
     prize: Point
 }
 
-fn parse(lines: &[&str], add: i64) -> Machine {
-    let a = scan_fmt!(lines[0], "Button A: X+{}, Y+{}", i64, i64).unwrap();
-    let b = scan_fmt!(lines[1], "Button B: X+{}, Y+{}", i64, i64).unwrap();
-    let prize = scan_fmt!(lines[2], "Prize: X={}, Y={}", i64, i64).unwrap();
+fn parse(block: &str, add: i64) -> Machine {
+    let (a, b, prize) = block.lines().collect_tuple().unwrap();
+    let a = scan_fmt!(a, "Button A: X+{}, Y+{}", i64, i64).unwrap();
+    let b = scan_fmt!(b, "Button B: X+{}, Y+{}", i64, i64).unwrap();
+    let prize = scan_fmt!(prize, "Prize: X={}, Y={}", i64, i64).unwrap();
     Machine {
         a: Point { x: a.0, y: a.1 },
         b: Point { x: b.0, y: b.1 },
@@ -31,7 +32,7 @@ fn parse(lines: &[&str], add: i64) -> Machine {
 }
 
 fn solve(machine: &Machine) -> i64 {
-    let mut best = i64::max_value();
+    let mut best = i64::MAX;
     let mut ans = false;
     for a in 0..=100 {
         for b in 0..=100 {
@@ -62,16 +63,16 @@ fn solve_eq(machine: &Machine) -> i64 {
 }
 
 #[advent_of_code(2024, 13, 1)]
-pub fn solve_level1(input: &[&str]) -> i64 {
-    let machines: Vec<_> = input.chunks(4).map(|chunk| parse(chunk, 0)).collect();
+pub fn solve_level1(input: &str) -> i64 {
+    let machines: Vec<_> = input.split("\n\n").map(|block| parse(block, 0)).collect();
     machines.iter()
         .map(solve)
         .sum()
 }
 
 #[advent_of_code(2024, 13, 2)]
-pub fn solve_level2(input: &[&str]) -> i64 {
-    let machines: Vec<_> = input.chunks(4).map(|chunk| parse(chunk, 10000000000000)).collect();
+pub fn solve_level2(input: &str) -> i64 {
+    let machines: Vec<_> = input.split("\n\n").map(|block| parse(block, 10000000000000)).collect();
 
     machines.iter()
         .map(solve_eq)
@@ -89,15 +90,13 @@ mod tests {
 
     #[test]
     fn test_level1() {
-        let input: Vec<_> = (*SAMPLE).lines().collect();
         let expected = (*SAMPLE_OUT).lines().next().unwrap();
-        assert_eq!(format!("{}", solve_level1(&input)), expected);
+        assert_eq!(format!("{}", solve_level1(&SAMPLE)), expected);
     }
 
     #[test]
     fn test_level2() {
-        let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
-        assert_eq!(format!("{}", solve_level2(&input)), expected);
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
+        assert_eq!(format!("{}", solve_level2(&SAMPLE)), expected);
     }
 }
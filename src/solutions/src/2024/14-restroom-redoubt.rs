@@ -1,5 +1,6 @@
 #![allow(unused_imports)]
 
+use aoc_core::Params;
 use aoc_macros::advent_of_code;
 use inventory;
 use scan_fmt::scan_fmt;
@@ -12,15 +13,12 @@ fn parse(line: &str) -> ((i32, i32), (i32, i32)) {
     ((x1, y1), (x2, y2))
 }
 
-#[advent_of_code(2024, 14, 1)]
-pub fn solve_level1(input: &[&str]) -> i64 {
+#[advent_of_code(2024, 14, 1, width = 101, height = 103)]
+pub fn solve_level1(input: &[&str], params: &Params) -> i64 {
     let mut robots: Vec<_> = input.iter().map(|&line| parse(line)).collect();
 
-    let width = 101;
-    let height = 103;
-
-    // let height = 7;
-    // let width = 11;
+    let width: i32 = params.get("width");
+    let height: i32 = params.get("height");
 
     for _sec in 0..100 {
         robots = robots.iter().copied().map(|((x, y), (dx, dy))| {
@@ -41,15 +39,12 @@ pub fn solve_level1(input: &[&str]) -> i64 {
     q1 * q2 * q3 * q4
 }
 
-#[advent_of_code(2024, 14, 2)]
-pub fn solve_level2(input: &[&str]) -> u32 {
+#[advent_of_code(2024, 14, 2, width = 101, height = 103)]
+pub fn solve_level2(input: &[&str], params: &Params) -> u32 {
     let mut robots: Vec<_> = input.iter().map(|&line| parse(line)).collect();
 
-    let width = 101;
-    let height = 103;
-
-    // let height = 7;
-    // let width = 11;
+    let width: i32 = params.get("width");
+    let height: i32 = params.get("height");
 
     let mut secs = 0;
     loop {
@@ -90,17 +85,21 @@ mod tests {
     static SAMPLE: LazyLock<String> = LazyLock::new(|| fs::read_to_string("../../input/2024/14-sample.in").unwrap());
     static SAMPLE_OUT: LazyLock<String> = LazyLock::new(|| fs::read_to_string("../../input/2024/14-sample.out").unwrap());
 
+    fn sample_params() -> Params {
+        Params::new(&[], &[("width".to_string(), "11".to_string()), ("height".to_string(), "7".to_string())])
+    }
+
     #[test]
     fn test_level1() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
         let expected = (*SAMPLE_OUT).lines().next().unwrap();
-        assert_eq!(format!("{}", solve_level1(&input)), expected);
+        assert_eq!(format!("{}", solve_level1(&input, &sample_params())), expected);
     }
 
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
-        assert_eq!(format!("{}", solve_level2(&input)), expected);
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
+        assert_eq!(format!("{}", solve_level2(&input, &sample_params())), expected);
     }
 }
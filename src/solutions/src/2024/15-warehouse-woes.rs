@@ -33,12 +33,11 @@ pub fn solve_level1(input: &[&str]) -> usize {
     let instructions: Vec<_> = input.iter().skip_while(|&l| !l.is_empty()).skip(1).join("").chars().collect();
 
     let width = map[0].len();
-    let height = map.len();
 
     let mut rx = 0; let mut ry = 0;
     for x in 0..width {
-        for y in 0..height {
-            if map[y][x] == '@' {
+        for (y, row) in map.iter().enumerate() {
+            if row[x] == '@' {
                 rx = x;
                 ry = y;
             }
@@ -83,9 +82,9 @@ pub fn solve_level1(input: &[&str]) -> usize {
     }
 
     let mut score = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if map[y][x] == 'O' {
+    for (y, row) in map.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if cell == 'O' {
                 score += (y*100) + x;
             }
         }
@@ -95,7 +94,7 @@ pub fn solve_level1(input: &[&str]) -> usize {
 }
 
 pub fn mv2(x: usize, y: usize, dx: i32, dy: i32, map: &mut Vec<Vec<char>>) -> bool {
-    let nx = (x as i32 + dx*1) as usize;
+    let nx = (x as i32 + dx) as usize;
     let nx2 = (x as i32 + dx*2) as usize;
     let ny = (y as i32 + dy) as usize;
     if map[ny][nx2] == '#' { return false; }
@@ -200,7 +199,7 @@ pub fn mv3(x: usize, y: usize, dx: i32, dy: i32, map: &mut Vec<Vec<char>>) -> bo
 pub fn solve_level2(input: &[&str]) -> usize {
     let mut map: Vec<Vec<_>> = input.iter().take_while(|&l| !l.is_empty())
                                            .map(|&line| {
-                                               line.chars().map(|c| {
+                                               line.chars().flat_map(|c| {
                                                    match c {
                                                        '#' => "##",
                                                        'O' => "[]",
@@ -208,20 +207,19 @@ pub fn solve_level2(input: &[&str]) -> usize {
                                                        '@' => "@.",
                                                        _ => panic!("Expected one of #,O,.,@"),
                                                    }.chars()
-                                               }).flatten().collect()
+                                               }).collect()
                                            }).collect();
     let instructions: Vec<_> = input.iter().skip_while(|&l| !l.is_empty()).skip(1).join("").chars().collect();
 
     let width = map[0].len();
-    let height = map.len();
 
     let mut rx = 0; let mut ry = 0;
     for x in 0..width {
-        for y in 0..height {
-            if map[y][x] == '@' {
+        for (y, row) in map.iter_mut().enumerate() {
+            if row[x] == '@' {
                 rx = x;
                 ry = y;
-                map[y][x] = '.';
+                row[x] = '.';
             }
         }
     }
@@ -284,9 +282,9 @@ pub fn solve_level2(input: &[&str]) -> usize {
         // }
 
     let mut score = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if map[y][x] == '[' {
+    for (y, row) in map.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if cell == '[' {
                 score += (y*100) + x;
             }
         }
@@ -314,7 +312,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -1,6 +1,6 @@
 #![allow(unused_imports)]
 
-use aoc_macros::advent_of_code;
+use aoc_macros::{advent_of_code, aoc_generator};
 use inventory;
 use scan_fmt::scan_fmt;
 use itertools::Itertools;
@@ -32,32 +32,42 @@ impl PartialOrd for State {
     }
 }
 
-#[advent_of_code(2024, 16, 1)]
-pub fn solve_level1(input: &[&str]) -> usize {
-    let map: Vec<Vec<char>> = input.iter().map(|&line| line.chars().collect()).collect();
+pub struct Maze {
+    map: Vec<Vec<char>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
 
-    let width = map[0].len();
-    let height = map.len();
+#[aoc_generator(2024, 16)]
+pub fn parse(input: &[&str]) -> Maze {
+    let map: Vec<Vec<char>> = input.iter().map(|&line| line.chars().collect()).collect();
 
-    let mut ex = 0; let mut ey = 0;
-    let mut sx = 0; let mut sy = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if map[y][x] == 'E' {
-                ex = x; ey = y;
-            }
-            if map[y][x] == 'S' {
-                sx = x; sy = y;
-            }
+    let mut start = (0, 0);
+    let mut end = (0, 0);
+    for (y, row) in map.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == 'S' { start = (x, y); }
+            if c == 'E' { end = (x, y); }
         }
     }
 
+    Maze { map, start, end }
+}
+
+#[advent_of_code(2024, 16, 1)]
+pub fn solve_level1(maze: &Maze) -> usize {
+    let map = &maze.map;
+    let width = map[0].len();
+    let height = map.len();
+    let (sx, sy) = maze.start;
+    let (ex, ey) = maze.end;
+
     // s, e, n, w
     let dx = [0, 1, 0, -1];
     let dy = [1, 0, -1, 0];
 
     let mut pq = BinaryHeap::new();
-    let mut dist = vec![vec![usize::max_value(); width]; height];
+    let mut dist = vec![vec![usize::MAX; width]; height];
 
     pq.push(State { x: sx, y: sy, c: 0, o: 1, p: FxHashSet::default() });
     dist[sy][sx] = 0;
@@ -86,31 +96,19 @@ pub fn solve_level1(input: &[&str]) -> usize {
 }
 
 #[advent_of_code(2024, 16, 2)]
-pub fn solve_level2(input: &[&str]) -> usize {
-    let map: Vec<Vec<char>> = input.iter().map(|&line| line.chars().collect()).collect();
-
+pub fn solve_level2(maze: &Maze) -> usize {
+    let map = &maze.map;
     let width = map[0].len();
     let height = map.len();
-
-    let mut sx = 0; let mut sy = 0;
-    let mut ex = 0; let mut ey = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if map[y][x] == 'E' {
-                ex = x; ey = y;
-            }
-            if map[y][x] == 'S' {
-                sx = x; sy = y;
-            }
-        }
-    }
+    let (sx, sy) = maze.start;
+    let (ex, ey) = maze.end;
 
     // s, e, n, w
     let dx = [0, 1, 0, -1];
     let dy = [1, 0, -1, 0];
 
     let mut pq = BinaryHeap::new();
-    let mut dist = vec![vec![vec![usize::max_value(); 4]; width]; height];
+    let mut dist = vec![vec![vec![usize::MAX; 4]; width]; height];
 
     let mut path = FxHashSet::default();
     path.insert((ex, ey));
@@ -118,12 +116,12 @@ pub fn solve_level2(input: &[&str]) -> usize {
     pq.push(State { x: sx, y: sy, c: 0, o: 1, p: path.clone() });
     dist[sy][sx][1] = 0;
 
-    let mut best_path_cost = usize::max_value();
+    let mut best_path_cost = usize::MAX;
     let mut all_paths: FxHashSet<(usize, usize)> = FxHashSet::default();
     while let Some(State { c, x, y, o, p }) = pq.pop() {
         // println!("Looking at ({}, {}), dir: {}, cost: {}", x, y, o, c);
         if x == ex && y == ey { // this is one of the best paths
-            if best_path_cost == usize::max_value() { best_path_cost = c };
+            if best_path_cost == usize::MAX { best_path_cost = c };
             if c == best_path_cost {
                 // println!("Found a path!");
                 all_paths.extend(&p);
@@ -168,13 +166,13 @@ mod tests {
     fn test_level1() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
         let expected = (*SAMPLE_OUT).lines().next().unwrap();
-        assert_eq!(format!("{}", solve_level1(&input)), expected);
+        assert_eq!(format!("{}", solve_level1(&parse(&input))), expected);
     }
 
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
-        assert_eq!(format!("{}", solve_level2(&input)), expected);
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
+        assert_eq!(format!("{}", solve_level2(&parse(&input))), expected);
     }
 }
@@ -30,7 +30,7 @@ impl Puter {
     fn get_combo_operand(&self) -> i64 {
         let val = self.program[self.ip + 1];
         match val {
-            0|1|2|3 => val as i64,
+            0..=3 => val as i64,
             4 => self.a,
             5 => self.b,
             6 => self.c,
@@ -70,13 +70,13 @@ fn run(mut program: Puter) -> Vec<u8> {
         // perform the opcode
         if opcode == ADV {
             let operand = program.get_combo_operand();
-            program.a = program.a / 2i64.pow(operand as u32);
+            program.a /= 2i64.pow(operand as u32);
             program.ip += 2;
         }
 
         if opcode == BXL {
             let operand = program.get_literal_operand();
-            program.b = program.b ^ operand;
+            program.b ^= operand;
             program.ip += 2;
         }
 
@@ -97,7 +97,7 @@ fn run(mut program: Puter) -> Vec<u8> {
 
         if opcode == BXC {
             let _operand = program.get_literal_operand(); // ignored, therefore may be invalid combo
-            program.b = program.b ^ program.c;
+            program.b ^= program.c;
             program.ip += 2;
         }
 
@@ -125,13 +125,7 @@ fn run(mut program: Puter) -> Vec<u8> {
 
 #[advent_of_code(2024, 17, 1)]
 pub fn solve_level1(input: &[&str]) -> String {
-    let out = format!("{:?}", run(parse_input(input)));
-
-    str::replace(
-        &str::replace(
-            &str::replace(&out, " ", ""),
-            "[", ""),
-        "]", "").to_string()
+    run(parse_input(input)).iter().join(",")
 }
 
 // Lucked out. This one works only on my own input
@@ -230,7 +224,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE2).lines().collect();
-        let expected = (*SAMPLE2_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE2_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -1,5 +1,6 @@
 #![allow(unused_imports)]
 
+use aoc_core::Params;
 use aoc_macros::advent_of_code;
 use inventory;
 use scan_fmt::scan_fmt;
@@ -28,9 +29,7 @@ pub fn solve1(input: &[&str], dim: usize, drop: usize) -> usize {
     let mut seen = vec![vec![false; dim]; dim];
 
     let mut nmap = FxHashSet::default();
-    for i in 0..drop {
-        let x = mem[i].0;
-        let y = mem[i].1;
+    for &(x, y) in &mem[..drop] {
         nmap.insert((x, y));
     }
 
@@ -55,9 +54,9 @@ pub fn solve1(input: &[&str], dim: usize, drop: usize) -> usize {
     0
 }
 
-#[advent_of_code(2024, 18, 1)]
-pub fn solve_level1(input: &[&str]) -> usize {
-    solve1(input, 71, 1024)
+#[advent_of_code(2024, 18, 1, dim = 71, drop = 1024)]
+pub fn solve_level1(input: &[&str], params: &Params) -> usize {
+    solve1(input, params.get("dim"), params.get("drop"))
 }
 
 pub fn solve2(input: &[&str], dim: usize) -> String {
@@ -66,9 +65,9 @@ pub fn solve2(input: &[&str], dim: usize) -> String {
     input[amounts_to_drop[first_zero_index]].to_string()
 }
 
-#[advent_of_code(2024, 18, 2)]
-pub fn solve_level2(input: &[&str]) -> String {
-    solve2(input, 71)
+#[advent_of_code(2024, 18, 2, dim = 71)]
+pub fn solve_level2(input: &[&str], params: &Params) -> String {
+    solve2(input, params.get("dim"))
 }
 
 #[cfg(test)]
@@ -90,7 +89,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve2(&input, 7)), expected);
     }
 }
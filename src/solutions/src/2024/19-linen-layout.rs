@@ -17,8 +17,8 @@ fn possible(requested: String, available: &[&str]) -> u64 {
 
     let mut found = 0;
     for a in available {
-        if requested.starts_with(a) {
-            found += possible(requested[a.len()..].to_string(), available);
+        if let Some(rest) = requested.strip_prefix(a) {
+            found += possible(rest.to_string(), available);
         }
     }
     found
@@ -59,7 +59,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
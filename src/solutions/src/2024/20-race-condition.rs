@@ -9,7 +9,7 @@ use std::collections::{BinaryHeap, VecDeque};
 use std::cmp::Ordering;
 use rustc_hash::FxHashSet;
 
-fn dist_from(grid: &Vec<Vec<char>>, start: (usize, usize)) -> Vec<Vec<usize>> {
+fn dist_from(grid: &[Vec<char>], start: (usize, usize)) -> Vec<Vec<usize>> {
     let dx = [0, 1, 0, -1];
     let dy = [-1, 0, 1, 0];
     let width = grid[0].len();
@@ -18,7 +18,7 @@ fn dist_from(grid: &Vec<Vec<char>>, start: (usize, usize)) -> Vec<Vec<usize>> {
 
     let mut q = VecDeque::new();
     q.push_back((sx, sy, 0));
-    let mut dists = vec![vec![usize::max_value(); width]; height];
+    let mut dists = vec![vec![usize::MAX; width]; height];
     dists[sy][sx] = 0;
     while let Some((x, y, c)) = q.pop_front() {
         for d in 0..4 {
@@ -34,19 +34,19 @@ fn dist_from(grid: &Vec<Vec<char>>, start: (usize, usize)) -> Vec<Vec<usize>> {
 }
 
 pub fn solve(input: &[&str], diff: usize, jump: usize) -> usize {
-    let grid = input.into_iter().map(|line| line.chars().collect_vec()).collect_vec();
+    let grid = input.iter().map(|line| line.chars().collect_vec()).collect_vec();
 
     let width = grid[0].len();
     let height = grid.len();
 
     let mut sx = 0; let mut sy = 0;
     let mut ex = 0; let mut ey = 0;
-    for y in 0..height {
-        for x in 0..width {
-            if grid[y][x] == 'S' {
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if cell == 'S' {
                 sx = x; sy = y;
             }
-            if grid[y][x] == 'E' {
+            if cell == 'E' {
                 ex = x; ey = y;
             }
         }
@@ -62,7 +62,7 @@ pub fn solve(input: &[&str], diff: usize, jump: usize) -> usize {
                 for nx in x.saturating_sub(jump)..width.min(x+jump+1) {
                     let dist = ((y as i64 - ny as i64).abs() + (x as i64 - nx as i64).abs()) as usize;
                     if grid[y][x] != '#' && grid[ny][nx] != '#' && dist <= jump {
-                        let cheated = dists[y][x] + diste[ny][nx] + (dist as usize) - 1;
+                        let cheated = dists[y][x] + diste[ny][nx] + dist - 1;
                         if cheated + diff < dists[ey][ex] {
                             count += 1;
                         }
@@ -104,7 +104,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -9,10 +9,10 @@ use std::collections::VecDeque;
 use memoize::memoize;
 use im::{Vector, vector};
 
-fn find(haystack: &Vec<Vec<char>>, needle: char) -> (usize, usize) {
+fn find(haystack: &[Vec<char>], needle: char) -> (usize, usize) {
     for x in 0..haystack[0].len() {
-        for y in 0..haystack.len() {
-            if haystack[y][x] == needle { return (x, y); }
+        for (y, row) in haystack.iter().enumerate() {
+            if row[x] == needle { return (x, y); }
         }
     }
     panic!("Did not find needle");
@@ -44,8 +44,8 @@ pub fn numpad(from: char, to: char, ndirpads: usize) -> usize {
 
     // println!("Going from ({:?}) to ({}, {})", find(&kp, from), ex, ey);
 
-    let mut ans = usize::max_value();
-    let mut dist = vec![vec![usize::max_value(); 3]; 4];
+    let mut ans = usize::MAX;
+    let mut dist = vec![vec![usize::MAX; 3]; 4];
     while let Some(((x, y), c, p)) = q.pop_front() {
         // println!("{},{}", x, y);
         if x == ex && y == ey {
@@ -58,7 +58,7 @@ pub fn numpad(from: char, to: char, ndirpads: usize) -> usize {
             for d in 0..4 {
                 let nx = (x as i64 + dx[d]) as usize;
                 let ny = (y as i64 + dy[d]) as usize;
-                if nx < 3 && ny < 4 && kp[ny][nx] != ' ' && c+1 <= dist[ny][nx] {
+                if nx < 3 && ny < 4 && kp[ny][nx] != ' ' && c < dist[ny][nx] {
                     // println!("  {},{}", nx, ny);
                     dist[ny][nx] = c+1;
                     let mut np = p.clone();
@@ -100,8 +100,8 @@ pub fn dirpad(from: char, to: char, ndirpads: usize) -> usize {
 
     // println!("Going from ({:?}) to ({}, {})", find(&kp, from), ex, ey);
 
-    let mut ans = usize::max_value();
-    let mut dist = vec![vec![usize::max_value(); 3]; 2];
+    let mut ans = usize::MAX;
+    let mut dist = vec![vec![usize::MAX; 3]; 2];
     while let Some(((x, y), c, p)) = q.pop_front() {
         // println!("{},{}", x, y);
         if x == ex && y == ey {
@@ -114,7 +114,7 @@ pub fn dirpad(from: char, to: char, ndirpads: usize) -> usize {
             for d in 0..4 {
                 let nx = (x as i64 + dx[d]) as usize;
                 let ny = (y as i64 + dy[d]) as usize;
-                if nx < 3 && ny < 2 && kp[ny][nx] != ' ' && c+1 <= dist[ny][nx] {
+                if nx < 3 && ny < 2 && kp[ny][nx] != ' ' && c < dist[ny][nx] {
                     // println!("  {},{}", nx, ny);
                     dist[ny][nx] = c+1;
                     let mut np = p.clone();
@@ -171,7 +171,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE).lines().collect();
-        let expected = (*SAMPLE_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -14,8 +14,7 @@ const MOD: i128 = 16777216;
 fn next(cur: i128) -> i128 {
     let p1 = (cur ^ (cur * 64)) % MOD;
     let p2 = (p1 ^ (p1 / 32)) % MOD;
-    let p3 = (p2 ^ (p2 * 2048)) % MOD;
-    p3
+    (p2 ^ (p2 * 2048)) % MOD
 }
 
 #[advent_of_code(2024, 22, 1)]
@@ -36,7 +35,7 @@ fn create_map_for_buyer(start: i128) -> FxHashMap<u32, i8> {
     let mut map = FxHashMap::default();
 
     let mut secret = start;
-    let mut encoded_steps = 0 as u32;
+    let mut encoded_steps = 0_u32;
     for i in 0..2000 {
         let p1 = (secret % 10) as i8;
         secret = next(secret);
@@ -60,7 +59,7 @@ fn create_map_for_buyer(start: i128) -> FxHashMap<u32, i8> {
 
 #[advent_of_code(2024, 22, 2)]
 pub fn solve_level2(input: &[&str]) -> u32 {
-    let buyers: Vector<i128> = input.into_iter().map(|l| l.parse::<i128>().unwrap()).collect();
+    let buyers: Vector<i128> = input.iter().map(|l| l.parse::<i128>().unwrap()).collect();
 
     let maps = buyers.into_iter().map(create_map_for_buyer);
     // merge maps
@@ -96,7 +95,7 @@ mod tests {
     #[test]
     fn test_level2() {
         let input: Vec<_> = (*SAMPLE2).lines().collect();
-        let expected = (*SAMPLE2_OUT).lines().skip(3).next().unwrap();
+        let expected = (*SAMPLE2_OUT).lines().nth(3).unwrap();
         assert_eq!(format!("{}", solve_level2(&input)), expected);
     }
 }
@@ -27,15 +27,15 @@ pub fn solve(wire: &String, wires: ImHashMap<String, u8>, gates: &FxHashMap<Stri
 
     let updated_wires = w.update(wire.clone(), ans);
 
-    return (ans, updated_wires);
+    (ans, updated_wires)
 }
 
 #[advent_of_code(2024, 24, 1)]
 pub fn solve_level1(input: &[&str]) -> u64 {
     let mut inp = input.split(|l| l.is_empty());
 
-    let init = inp.next().unwrap().into_iter().map(|l| scan_fmt!(l, "{}: {}", String, u8).unwrap()).collect_vec();
-    let gates_i = inp.next().unwrap().into_iter().map(|l| scan_fmt!(l, "{} {} {} -> {}", String, String, String, String).unwrap()).collect_vec();
+    let init = inp.next().unwrap().iter().map(|l| scan_fmt!(l, "{}: {}", String, u8).unwrap()).collect_vec();
+    let gates_i = inp.next().unwrap().iter().map(|l| scan_fmt!(l, "{} {} {} -> {}", String, String, String, String).unwrap()).collect_vec();
 
     let mut wires: ImHashMap<String, u8> = ImHashMap::new();
     for (w, v) in init {
@@ -73,8 +73,8 @@ pub fn solve_level1(input: &[&str]) -> u64 {
 pub fn solve_level2(input: &[&str]) -> String {
     let mut inp = input.split(|l| l.is_empty());
 
-    let init = inp.next().unwrap().into_iter().map(|l| scan_fmt!(l, "{}: {}", String, u8).unwrap()).collect_vec();
-    let gates = inp.next().unwrap().into_iter().copied().collect_vec();
+    let init = inp.next().unwrap().iter().map(|l| scan_fmt!(l, "{}: {}", String, u8).unwrap()).collect_vec();
+    let gates = inp.next().unwrap().iter().copied().collect_vec();
 
     let swapped = swap_all(45, &gates);
 
@@ -103,7 +103,7 @@ fn find<'a>(a: Option<&str>, operator: &str, b: Option<&str>, gates: &[&'a str])
             })
             .and_then(|gate| {
                 // Split on " -> " and take the last piece
-                gate.split(" -> ").last().map(|s| s)
+                gate.split(" -> ").last()
             }),
         _ => None
     }
@@ -6,30 +6,23 @@ use inventory;
 use scan_fmt::scan_fmt;
 use itertools::Itertools;
 
-pub fn to_heights(lk: &Vec<Vec<char>>, chr: char) -> Vec<usize> {
+pub fn to_heights(lk: &[Vec<char>], chr: char) -> Vec<usize> {
     let mut v: Vec<usize> = vec![];
     let w = lk[0].len();
     let h = lk.len();
 
     for x in 0..w {
-        let mut count = 0;
-        for y in 0..h {
-            if lk[y][x] == chr {
-                count += 1
-            }
-        }
-        v.push(count);
+        v.push(lk.iter().filter(|row| row[x] == chr).count());
     }
     v
 }
 
 #[advent_of_code(2024, 25, 1)]
-pub fn solve_level1(input: &[&str]) -> u32 {
-    let mut splitted = input.split(|l| l.is_empty());
+pub fn solve_level1(input: &str) -> u32 {
     let mut keys = vec![];
     let mut locks = vec![];
-    while let Some(lk) = splitted.next() {
-        let x = lk.iter().map(|l| l.chars().collect_vec()).collect_vec();
+    for lk in input.split("\n\n") {
+        let x = lk.lines().map(|l| l.chars().collect_vec()).collect_vec();
         if x[0][0] == '.' {
             keys.push(to_heights(&x, '#'));
         } else {
@@ -65,8 +58,7 @@ mod tests {
 
     #[test]
     fn test_level1() {
-        let input: Vec<_> = (*SAMPLE).lines().collect();
         let expected = (*SAMPLE_OUT).lines().next().unwrap();
-        assert_eq!(format!("{}", solve_level1(&input)), expected);
+        assert_eq!(format!("{}", solve_level1(&SAMPLE)), expected);
     }
 }